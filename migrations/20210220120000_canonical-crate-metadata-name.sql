@@ -0,0 +1,9 @@
+delete
+from crate_metadata as a
+    using crate_metadata as b
+where lower(replace(a.name, '_', '-')) = lower(replace(b.name, '_', '-'))
+  and a.version = b.version
+  and a.id > b.id;
+
+update crate_metadata
+set name = lower(replace(name, '_', '-'));
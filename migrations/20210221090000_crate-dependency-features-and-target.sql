@@ -0,0 +1,8 @@
+delete
+from crate_metadata;
+
+alter table crate_dependency
+    add column optional         boolean not null default false,
+    add column default_features boolean not null default true,
+    add column features         text[]  not null default '{}',
+    add column target           text;
@@ -0,0 +1,10 @@
+delete
+from crate_metadata;
+
+drop index crate_dependency_name_type_crate_id_uindex;
+
+alter table crate_dependency
+    add column position integer not null;
+
+create unique index crate_dependency_crate_id_position_uindex
+    on crate_dependency (crate_id, position);
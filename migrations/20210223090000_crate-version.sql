@@ -0,0 +1,16 @@
+create table crate_version
+(
+    id           serial      not null,
+    name         varchar(64) not null,
+    version      varchar(40) not null,
+    created_at   timestamptz not null,
+    yanked       boolean     not null,
+    checksum     varchar(64) not null,
+    rust_version varchar(40),
+    features     jsonb       not null default '{}',
+    constraint crate_version_pk
+        primary key (id)
+);
+
+create unique index crate_version_name_version_uindex
+    on crate_version (name, version);
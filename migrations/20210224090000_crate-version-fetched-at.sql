@@ -0,0 +1,2 @@
+alter table crate_version
+    add column fetched_at timestamptz not null default now();
@@ -0,0 +1,5 @@
+delete
+from crate_version;
+
+alter table crate_version
+    add column releases integer not null;
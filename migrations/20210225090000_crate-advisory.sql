@@ -0,0 +1,14 @@
+create table crate_advisory
+(
+    id            varchar(64) not null,
+    name          varchar(64) not null,
+    severity      varchar(16),
+    informational varchar(64),
+    patched       text[]      not null,
+    unaffected    text[]      not null,
+    constraint crate_advisory_pk
+        primary key (id)
+);
+
+create index crate_advisory_name_index
+    on crate_advisory (name);
@@ -0,0 +1,61 @@
+use crate::domain::CrateAdvisory;
+use crate::telemetry::TraceErrorExt;
+use std::path::{Path, PathBuf};
+
+/// A checkout of the RustSec advisory database on disk.
+pub struct AdvisoryDatabase {
+    directory: PathBuf,
+}
+
+impl AdvisoryDatabase {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Reads every advisory below the directory, leaving out withdrawn advisories. Advisories
+    /// that cannot be parsed are skipped, so a format change only drops the affected files.
+    #[tracing::instrument(skip(self), fields(directory = %self.directory.display()))]
+    pub fn scan(&self) -> Result<Vec<CrateAdvisory>, std::io::Error> {
+        let mut paths = Vec::new();
+        find_advisories(&self.directory, &mut paths).trace_err()?;
+        paths.sort();
+
+        let mut advisories = Vec::with_capacity(paths.len());
+        for path in paths {
+            let value = std::fs::read_to_string(&path).trace_err()?;
+
+            match CrateAdvisory::parse(&value) {
+                Ok(advisory) if advisory.withdrawn => {}
+                Ok(advisory) => advisories.push(advisory),
+                Err(error) => tracing::warn!(path = %path.display(), error = %error),
+            }
+        }
+
+        tracing::info!(advisories = %advisories.len(), "scanned advisories");
+
+        Ok(advisories)
+    }
+}
+
+fn find_advisories(directory: &Path, paths: &mut Vec<PathBuf>) -> Result<(), std::io::Error> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if path.is_dir() {
+            if !name.starts_with('.') {
+                find_advisories(&path, paths)?;
+            }
+        } else if name.starts_with("RUSTSEC-") && (name.ends_with(".md") || name.ends_with(".toml"))
+        {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
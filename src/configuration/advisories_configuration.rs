@@ -0,0 +1,19 @@
+use crate::advisory_database::AdvisoryDatabase;
+
+#[derive(Clone, Default, serde::Deserialize)]
+pub struct AdvisoriesConfiguration {
+    /// Checkout of the RustSec advisory database, advisories are not loaded when it is missing.
+    pub directory: Option<String>,
+    /// Bearer token required to reload advisories, reloading is refused when it is missing.
+    pub reload_token: Option<String>,
+}
+
+impl AdvisoriesConfiguration {
+    pub fn database(&self) -> Option<AdvisoryDatabase> {
+        self.directory.as_deref().map(AdvisoryDatabase::new)
+    }
+
+    pub fn authorizes_reload(&self, token: Option<&str>) -> bool {
+        matches!((self.reload_token.as_deref(), token), (Some(expected), Some(token)) if expected == token)
+    }
+}
@@ -1,6 +1,8 @@
+mod advisories_configuration;
 mod crates_io_configuration;
 mod environment;
 mod http_server_configuration;
+mod policy_configuration;
 mod postgres_configuration;
 mod redis_configuration;
 
@@ -10,15 +12,21 @@ use environment::Environment;
 use std::convert::TryInto;
 use std::env;
 
+pub use advisories_configuration::*;
 pub use crates_io_configuration::*;
 pub use http_server_configuration::*;
+pub use policy_configuration::*;
 pub use postgres_configuration::*;
 pub use redis_configuration::*;
 
 #[derive(serde::Deserialize)]
 pub struct Configuration {
+    #[serde(default)]
+    pub advisories: AdvisoriesConfiguration,
     pub crates_io: CratesIoConfiguration,
     pub http_server: HttpServerConfiguration,
+    #[serde(default)]
+    pub policy: PolicyConfiguration,
     pub postgres: PostgresConfiguration,
     pub redis: RedisConfiguration,
 }
@@ -0,0 +1,23 @@
+use crate::domain::CratePolicy;
+use std::fs;
+
+#[derive(Default, serde::Deserialize)]
+pub struct PolicyConfiguration {
+    /// Policy file that dependency graphs are checked against, nothing is checked when it is
+    /// missing.
+    pub file: Option<String>,
+}
+
+impl PolicyConfiguration {
+    pub fn policy(&self) -> Result<Option<CratePolicy>, String> {
+        let file = match &self.file {
+            Some(file) => file,
+            None => return Ok(None),
+        };
+
+        let value =
+            fs::read_to_string(file).map_err(|e| format!("{} could not be read: {}.", file, e))?;
+
+        CratePolicy::parse(&value).map(Some)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::crates_io_client::CratesIoClient;
 use crate::domain::{
-    CrateDependency, CrateDependencyType, CrateMetadata, CrateName, CrateRequirement, CrateVersion,
+    CrateDependency, CrateDependencyType, CrateMetadata, CrateName, CrateRequirement, CrateTarget,
+    CrateVersion,
 };
+use crate::telemetry::TraceErrorExt;
 use std::convert::TryFrom;
 
 #[derive(Debug, serde::Deserialize)]
@@ -11,11 +13,11 @@ struct Response {
 }
 
 #[derive(Debug, serde::Deserialize)]
-struct DependencyResponse {
+pub(super) struct DependencyResponse {
     #[serde(rename = "id")]
     id: i64,
     #[serde(rename = "version_id")]
-    version_id: i64,
+    pub(super) version_id: i64,
     #[serde(rename = "crate_id")]
     crate_id: String,
     #[serde(rename = "req")]
@@ -34,19 +36,38 @@ struct DependencyResponse {
     downloads: i64,
 }
 
+impl DependencyResponse {
+    pub(super) fn to_dependency(&self) -> Result<CrateDependency, String> {
+        Ok(CrateDependency {
+            name: CrateName::parse(&self.crate_id)?,
+            requirement: CrateRequirement::parse(&self.req)?,
+            type_: CrateDependencyType::try_from(self.kind.as_str())?,
+            optional: self.optional,
+            default_features: self.default_features,
+            features: self.features.clone().unwrap_or_default(),
+            target: self.target.as_deref().map(CrateTarget::parse).transpose()?,
+        })
+    }
+}
+
 impl CratesIoClient {
+    /// Fails when crates.io serves a dependency this service cannot parse, rather than returning
+    /// a partial list of dependencies that would then be cached.
     pub async fn dependencies(
         &self,
         name: &CrateName,
         version: &CrateVersion,
-    ) -> Option<CrateMetadata> {
+    ) -> Result<Option<CrateMetadata>, String> {
         let url = format!(
             "/api/v1/crates/{}/{}/dependencies",
             name.as_str(),
             version.as_str()
         );
 
-        let response = self.get::<Response>(&url).await?;
+        let response = match self.get::<Response>(&url).await {
+            Some(response) => response,
+            None => return Ok(None),
+        };
 
         let result = CrateMetadata {
             name: name.clone(),
@@ -54,15 +75,12 @@ impl CratesIoClient {
             dependencies: response
                 .dependencies
                 .iter()
-                .map(|dependency| CrateDependency {
-                    name: CrateName::parse(&dependency.crate_id).unwrap(),
-                    requirement: CrateRequirement::parse(&dependency.req).unwrap(),
-                    type_: CrateDependencyType::try_from(dependency.kind.as_str()).unwrap(),
-                })
-                .collect(),
+                .map(DependencyResponse::to_dependency)
+                .collect::<Result<_, _>>()
+                .trace_err()?,
         };
 
-        Some(result)
+        Ok(Some(result))
     }
 }
 
@@ -99,6 +117,7 @@ mod tests {
                 &CrateVersion::parse("1.0.24").unwrap(),
             )
             .await
+            .unwrap()
             .unwrap();
 
         // Assert
@@ -108,9 +127,57 @@ mod tests {
         assert_eq!(&"quote", &result.dependencies[0].name.as_str());
         assert_eq!(&"^1.0", &result.dependencies[0].requirement.as_str());
         assert_eq!(&"dev", &result.dependencies[0].type_.as_str());
+        assert!(!result.dependencies[0].optional);
+        assert!(!result.dependencies[0].default_features);
+        assert!(result.dependencies[0].features.is_empty());
+        assert_eq!(None, result.dependencies[0].target);
         assert_eq!(&"unicode-xid", &result.dependencies[1].name.as_str());
         assert_eq!(&"^0.2", &result.dependencies[1].requirement.as_str());
         assert_eq!(&"normal", &result.dependencies[1].type_.as_str());
+        assert!(!result.dependencies[1].optional);
+        assert!(result.dependencies[1].default_features);
+        assert!(result.dependencies[1].features.is_empty());
+        assert_eq!(None, result.dependencies[1].target);
+    }
+
+    #[actix_rt::test]
+    async fn dependencies_returns_200_with_optional_and_target_specific_dependencies() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/crates/rand/0.8.3/dependencies"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("rand-0.8.3.json")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &Faker.fake::<String>()).unwrap();
+
+        // Act
+        let result = client
+            .dependencies(
+                &CrateName::parse("rand").unwrap(),
+                &CrateVersion::parse("0.8.3").unwrap(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(10, result.dependencies.len());
+
+        let libc = &result.dependencies[1];
+        assert_eq!("libc", libc.name.as_str());
+        assert!(!libc.optional);
+        assert!(!libc.default_features);
+        assert_eq!(Some(CrateTarget::parse("cfg(unix)").unwrap()), libc.target);
+
+        let packed_simd = &result.dependencies[3];
+        assert_eq!("packed_simd_2", packed_simd.name.as_str());
+        assert!(packed_simd.optional);
+        assert!(packed_simd.default_features);
+        assert_eq!(vec!["into_bits".to_owned()], packed_simd.features);
+        assert_eq!(None, packed_simd.target);
     }
 
     #[actix_rt::test]
@@ -128,15 +195,43 @@ mod tests {
         // Act
         let result = client
             .dependencies(
-                &CrateName::parse(&Faker.fake::<String>()).unwrap(),
-                &CrateVersion::parse(&Faker.fake::<String>()).unwrap(),
+                &CrateName::parse("not-found").unwrap(),
+                &CrateVersion::parse("1.0.0").unwrap(),
             )
-            .await;
+            .await
+            .unwrap();
 
         // Assert
         assert!(result.is_none());
     }
 
+    #[actix_rt::test]
+    async fn dependencies_returns_error_when_a_dependency_cannot_be_parsed() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/crates/unparseable/1.0.0/dependencies"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_bytes(fixture("unparseable-1.0.0.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &Faker.fake::<String>()).unwrap();
+
+        // Act
+        let result = client
+            .dependencies(
+                &CrateName::parse("unparseable").unwrap(),
+                &CrateVersion::parse("1.0.0").unwrap(),
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+
     fn fixture(filename: &str) -> Vec<u8> {
         let path = env::current_dir()
             .unwrap()
@@ -2,6 +2,8 @@ use crate::telemetry::TraceErrorExt;
 use reqwest::StatusCode;
 
 mod dependencies;
+mod reverse_dependencies;
+mod versions;
 
 pub struct CratesIoClient {
     base_address: String,
@@ -0,0 +1,140 @@
+use crate::crates_io_client::dependencies::DependencyResponse;
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CrateDependent, CrateName, CrateVersion};
+use crate::telemetry::TraceErrorExt;
+use std::collections::HashMap;
+
+const PER_PAGE: u32 = 100;
+
+#[derive(Debug, serde::Deserialize)]
+struct Response {
+    #[serde(rename = "dependencies")]
+    dependencies: Vec<DependencyResponse>,
+    #[serde(rename = "versions")]
+    versions: Vec<VersionResponse>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct VersionResponse {
+    #[serde(rename = "id")]
+    id: i64,
+    #[serde(rename = "crate")]
+    crate_: String,
+    #[serde(rename = "num")]
+    num: String,
+}
+
+impl CratesIoClient {
+    /// Returns a page of the crates whose latest version depends on `name`, starting at page 1.
+    pub async fn reverse_dependencies(
+        &self,
+        name: &CrateName,
+        page: u32,
+    ) -> Option<Vec<CrateDependent>> {
+        let url = format!(
+            "/api/v1/crates/{}/reverse_dependencies?page={}&per_page={}",
+            name.as_str(),
+            page,
+            PER_PAGE
+        );
+
+        let response = self.get::<Response>(&url).await?;
+
+        let versions = response
+            .versions
+            .iter()
+            .map(|version| (version.id, version))
+            .collect::<HashMap<_, _>>();
+
+        let result = response
+            .dependencies
+            .iter()
+            .filter_map(|dependency| {
+                let version = versions.get(&dependency.version_id)?;
+                Some(CrateDependent {
+                    name: CrateName::parse(&version.crate_).trace_err().ok()?,
+                    version: CrateVersion::parse(&version.num).trace_err().ok()?,
+                    dependency: dependency.to_dependency().trace_err().ok()?,
+                })
+            })
+            .collect();
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+    use std::env;
+    use wiremock::matchers::{any, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[actix_rt::test]
+    async fn reverse_dependencies_returns_200() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/crates/unicode-xid/reverse_dependencies"))
+            .and(query_param("page", "2"))
+            .and(query_param("per_page", "100"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(fixture("unicode-xid-reverse-dependencies.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &Faker.fake::<String>()).unwrap();
+
+        // Act
+        let result = client
+            .reverse_dependencies(&CrateName::parse("unicode-xid").unwrap(), 2)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(3, result.len());
+        assert_eq!("proc-macro2", result[0].name.as_str());
+        assert_eq!("1.0.24", result[0].version.as_str());
+        assert_eq!("unicode-xid", result[0].dependency.name.as_str());
+        assert_eq!("^0.2", result[0].dependency.requirement.as_str());
+        assert_eq!("xml-rs", result[1].name.as_str());
+        assert_eq!("^0.0.3", result[1].dependency.requirement.as_str());
+        assert_eq!("unicode-normalization", result[2].name.as_str());
+        assert_eq!("dev", result[2].dependency.type_.as_str());
+    }
+
+    #[actix_rt::test]
+    async fn reverse_dependencies_returns_404() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &Faker.fake::<String>()).unwrap();
+
+        // Act
+        let result = client
+            .reverse_dependencies(&CrateName::parse("unicode-xid").unwrap(), 1)
+            .await;
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    fn fixture(filename: &str) -> Vec<u8> {
+        let path = env::current_dir()
+            .unwrap()
+            .join("tests")
+            .join("fixtures")
+            .join(filename);
+
+        std::fs::read(path).unwrap()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CrateFeatures, CrateName, CrateRelease, CrateVersion};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+#[derive(Debug, serde::Deserialize)]
+struct Response {
+    #[serde(rename = "versions")]
+    versions: Vec<VersionResponse>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct VersionResponse {
+    #[serde(rename = "num")]
+    num: String,
+    #[serde(rename = "created_at")]
+    created_at: String,
+    #[serde(rename = "yanked")]
+    yanked: bool,
+    #[serde(rename = "checksum")]
+    checksum: String,
+    #[serde(rename = "rust_version")]
+    rust_version: Option<String>,
+    #[serde(rename = "license")]
+    license: Option<String>,
+    #[serde(rename = "features", default)]
+    features: BTreeMap<String, Vec<String>>,
+}
+
+impl CratesIoClient {
+    pub async fn versions(&self, name: &CrateName) -> Option<Vec<CrateRelease>> {
+        let url = format!("/api/v1/crates/{}", name.as_str());
+
+        let response = self.get::<Response>(&url).await?;
+
+        let result = response
+            .versions
+            .into_iter()
+            .filter_map(|version| {
+                Some(CrateRelease {
+                    version: CrateVersion::parse(&version.num).ok()?,
+                    created_at: DateTime::parse_from_rfc3339(&version.created_at)
+                        .ok()?
+                        .with_timezone(&Utc),
+                    yanked: version.yanked,
+                    checksum: version.checksum,
+                    rust_version: version.rust_version,
+                    license: version.license,
+                    features: CrateFeatures::new(version.features),
+                })
+            })
+            .collect();
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+    use std::env;
+    use wiremock::matchers::{any, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[actix_rt::test]
+    async fn versions_returns_200() {
+        // Arrange
+        let user_agent: String = Faker.fake();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/crates/unicode-xid"))
+            .and(header("user-agent", user_agent.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("unicode-xid.json")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &user_agent).unwrap();
+
+        // Act
+        let result = client
+            .versions(&CrateName::parse("unicode-xid").unwrap())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(3, result.len());
+        assert_eq!("0.2.1", result[0].version.as_str());
+        assert_eq!("0.2.0", result[1].version.as_str());
+        assert_eq!("0.1.0", result[2].version.as_str());
+        assert_eq!(
+            "2020-06-09T02:31:02.432914+00:00",
+            result[0].created_at.to_rfc3339()
+        );
+        assert!(!result[0].yanked);
+        assert_eq!(
+            "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564",
+            result[0].checksum
+        );
+        assert_eq!(None, result[0].rust_version);
+        assert_eq!(Some("MIT OR Apache-2.0".to_owned()), result[0].license);
+        assert_eq!(Some("MIT/Apache-2.0".to_owned()), result[2].license);
+        assert_eq!(
+            vec!["bench", "default", "no_std"],
+            result[0].features.as_map().keys().collect::<Vec<_>>()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn versions_returns_404() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CratesIoClient::new(&server.uri(), &Faker.fake::<String>()).unwrap();
+
+        // Act
+        let result = client
+            .versions(&CrateName::parse("not-found").unwrap())
+            .await;
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    fn fixture(filename: &str) -> Vec<u8> {
+        let path = env::current_dir()
+            .unwrap()
+            .join("tests")
+            .join("fixtures")
+            .join(filename);
+
+        std::fs::read(path).unwrap()
+    }
+}
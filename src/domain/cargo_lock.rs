@@ -0,0 +1,317 @@
+use crate::domain::{CrateName, CrateVersion};
+use serde::Deserialize;
+use std::fmt;
+
+pub const CRATES_IO_SOURCE: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CargoLock {
+    pub packages: Vec<CargoLockPackage>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CargoLockPackage {
+    pub name: CrateName,
+    pub version: CrateVersion,
+    pub source: Option<String>,
+    pub checksum: Option<String>,
+    pub dependencies: Vec<(CrateName, CrateVersion)>,
+}
+
+#[derive(Deserialize)]
+struct CargoLockToml {
+    #[serde(rename = "package", default)]
+    packages: Vec<CargoLockPackageToml>,
+}
+
+#[derive(Deserialize)]
+struct CargoLockPackageToml {
+    name: String,
+    version: String,
+    source: Option<String>,
+    checksum: Option<String>,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+impl CargoLock {
+    /// Orders packages and their dependencies by name and version, as Cargo writes them.
+    pub fn new(mut packages: Vec<CargoLockPackage>) -> Self {
+        for package in &mut packages {
+            package
+                .dependencies
+                .sort_by(|(a_name, a_version), (b_name, b_version)| {
+                    (a_name.as_str(), a_version).cmp(&(b_name.as_str(), b_version))
+                });
+            package.dependencies.dedup();
+        }
+
+        packages.sort_by(|a, b| (a.name.as_str(), &a.version).cmp(&(b.name.as_str(), &b.version)));
+
+        Self { packages }
+    }
+
+    /// Parses the packages of a lockfile. Dependencies given by name alone are matched to the only
+    /// package of that name, sources of dependencies are not needed to tell packages apart.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let lockfile = toml::from_str::<CargoLockToml>(value)
+            .map_err(|e| format!("Cargo.lock is not valid: {}.", e))?;
+
+        let mut packages = lockfile
+            .packages
+            .iter()
+            .map(|package| {
+                Ok(CargoLockPackage {
+                    name: CrateName::parse(&package.name)?,
+                    version: CrateVersion::parse(&package.version)?,
+                    source: package.source.clone(),
+                    checksum: package.checksum.clone(),
+                    dependencies: Vec::new(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        for (index, package) in lockfile.packages.iter().enumerate() {
+            for dependency in &package.dependencies {
+                let mut parts = dependency.split_whitespace();
+                let name = CrateName::parse(parts.next().unwrap_or_default())?;
+                let version = parts.next().map(CrateVersion::parse).transpose()?;
+
+                let mut candidates = packages.iter().filter(|package| {
+                    package.name == name
+                        && version.iter().all(|version| &package.version == version)
+                });
+
+                let version = match (candidates.next(), candidates.next()) {
+                    (Some(candidate), None) => candidate.version.clone(),
+                    _ => {
+                        return Err(format!(
+                            "{} is not a locked package. Use the name and version of a package in Cargo.lock.",
+                            dependency
+                        ))
+                    }
+                };
+
+                packages[index].dependencies.push((name, version));
+            }
+        }
+
+        Ok(Self::new(packages))
+    }
+
+    fn has_single_version(&self, name: &CrateName) -> bool {
+        self.packages
+            .iter()
+            .filter(|package| &package.name == name)
+            .count()
+            <= 1
+    }
+}
+
+/// Writes the lockfile in the version 3 format. Dependencies are referred to by name alone unless
+/// several versions of the crate are locked.
+impl fmt::Display for CargoLock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# This file is automatically @generated by Cargo.")?;
+        writeln!(f, "# It is not intended for manual editing.")?;
+        writeln!(f, "version = 3")?;
+
+        for package in &self.packages {
+            writeln!(f)?;
+            writeln!(f, "[[package]]")?;
+            writeln!(f, "name = \"{}\"", package.name.as_str())?;
+            writeln!(f, "version = \"{}\"", package.version.as_str())?;
+
+            if let Some(source) = &package.source {
+                writeln!(f, "source = \"{}\"", source)?;
+            }
+
+            if let Some(checksum) = &package.checksum {
+                writeln!(f, "checksum = \"{}\"", checksum)?;
+            }
+
+            if !package.dependencies.is_empty() {
+                writeln!(f, "dependencies = [")?;
+                for (name, version) in &package.dependencies {
+                    if self.has_single_version(name) {
+                        writeln!(f, " \"{}\",", name.as_str())?;
+                    } else {
+                        writeln!(f, " \"{} {}\",", name.as_str(), version.as_str())?;
+                    }
+                }
+                writeln!(f, "]")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let lockfile = CargoLock::parse(
+            r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "e0"
+dependencies = [
+ "unicode-xid 0.2.1 (registry+https://github.com/rust-lang/crates.io-index)",
+]
+
+[[package]]
+name = "rust-kata-003"
+version = "0.1.0"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fe"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CargoLock::new(vec![
+                package(
+                    "proc-macro2",
+                    "1.0.24",
+                    Some("e0"),
+                    &[("unicode-xid", "0.2.1")]
+                ),
+                package(
+                    "rust-kata-003",
+                    "0.1.0",
+                    None,
+                    &[("proc-macro2", "1.0.24"), ("unicode-xid", "0.1.0")],
+                ),
+                package("unicode-xid", "0.1.0", Some("fc"), &[]),
+                package("unicode-xid", "0.2.1", Some("fe"), &[]),
+            ]),
+            lockfile
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_lockfiles() {
+        let test_cases = vec![
+            ("[[package]]\nname = \"a\"", "Cargo.lock is not valid: "),
+            (
+                "[[package]]\nname = \"a\"\nversion = \"1\"",
+                "1 is not a valid semantic version: ",
+            ),
+            (
+                "[[package]]\nname = \"a\"\nversion = \"1.0.0\"\ndependencies = [\"b\"]",
+                "b is not a locked package. ",
+            ),
+            (
+                "[[package]]\nname = \"a\"\nversion = \"1.0.0\"\ndependencies = [\"a 2.0.0\"]",
+                "a 2.0.0 is not a locked package. ",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            let result = CargoLock::parse(value).unwrap_err();
+            assert!(result.starts_with(expected), "{}", result);
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        let lockfile = CargoLock::new(vec![
+            package("unicode-xid", "0.2.1", Some("fe"), &[]),
+            package(
+                "rust-kata-003",
+                "0.1.0",
+                None,
+                &[("unicode-xid", "0.1.0"), ("proc-macro2", "1.0.24")],
+            ),
+            package(
+                "proc-macro2",
+                "1.0.24",
+                Some("e0"),
+                &[("unicode-xid", "0.2.1")],
+            ),
+            package("unicode-xid", "0.1.0", Some("fc"), &[]),
+        ]);
+
+        assert_eq!(
+            r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "e0"
+dependencies = [
+ "unicode-xid 0.2.1",
+]
+
+[[package]]
+name = "rust-kata-003"
+version = "0.1.0"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fe"
+"#,
+            lockfile.to_string()
+        );
+    }
+
+    fn package(
+        name: &str,
+        version: &str,
+        checksum: Option<&str>,
+        dependencies: &[(&str, &str)],
+    ) -> CargoLockPackage {
+        CargoLockPackage {
+            name: CrateName::parse(name).unwrap(),
+            version: CrateVersion::parse(version).unwrap(),
+            source: checksum.map(|_| CRATES_IO_SOURCE.to_owned()),
+            checksum: checksum.map(str::to_owned),
+            dependencies: dependencies
+                .iter()
+                .map(|(name, version)| {
+                    (
+                        CrateName::parse(name).unwrap(),
+                        CrateVersion::parse(version).unwrap(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CfgExpression {
+    All(Vec<CfgExpression>),
+    Any(Vec<CfgExpression>),
+    Not(Box<CfgExpression>),
+    Name(String),
+    KeyValue(String, String),
+}
+
+impl CfgExpression {
+    /// Evaluates the expression, using `is_set` to look up whether a `name` or `key = "value"`
+    /// option is set.
+    pub fn evaluate<F>(&self, is_set: &F) -> bool
+    where
+        F: Fn(&str, Option<&str>) -> bool,
+    {
+        match self {
+            CfgExpression::All(expressions) => expressions.iter().all(|e| e.evaluate(is_set)),
+            CfgExpression::Any(expressions) => expressions.iter().any(|e| e.evaluate(is_set)),
+            CfgExpression::Not(expression) => !expression.evaluate(is_set),
+            CfgExpression::Name(name) => is_set(name, None),
+            CfgExpression::KeyValue(key, value) => is_set(key, Some(value)),
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        Self::parse_within(value, 0, value.len())
+    }
+
+    /// Parses the expression found between `start` and `end` of `value`, so errors report the
+    /// whole value and positions within it.
+    pub(crate) fn parse_within(value: &str, start: usize, end: usize) -> Result<Self, String> {
+        let mut parser = Parser {
+            source: value,
+            value: &value[..end],
+            position: start,
+        };
+
+        let expression = parser.expression()?;
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            None => Ok(expression),
+            Some(c) => Err(parser.error(&format!("unexpected `{}`", c))),
+        }
+    }
+}
+
+impl fmt::Display for CfgExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, name: &str, expressions: &[CfgExpression]| {
+            write!(f, "{}(", name)?;
+            for (i, expression) in expressions.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", expression)?;
+            }
+            write!(f, ")")
+        };
+
+        match self {
+            CfgExpression::All(expressions) => list(f, "all", expressions),
+            CfgExpression::Any(expressions) => list(f, "any", expressions),
+            CfgExpression::Not(expression) => write!(f, "not({})", expression),
+            CfgExpression::Name(name) => write!(f, "{}", name),
+            CfgExpression::KeyValue(key, value) => write!(f, "{} = \"{}\"", key, value),
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    value: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn expression(&mut self) -> Result<CfgExpression, String> {
+        self.skip_whitespace();
+        let start = self.position;
+        let identifier = self.identifier()?;
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('(') => {
+                self.position += 1;
+                let mut expressions = self.list()?;
+                match identifier {
+                    "all" => Ok(CfgExpression::All(expressions)),
+                    "any" => Ok(CfgExpression::Any(expressions)),
+                    "not" if expressions.len() == 1 => {
+                        Ok(CfgExpression::Not(Box::new(expressions.remove(0))))
+                    }
+                    "not" => Err(self.error_at(
+                        start,
+                        &format!(
+                            "`not` expects exactly one expression but found {}",
+                            expressions.len()
+                        ),
+                    )),
+                    other => Err(self.error_at(
+                        start,
+                        &format!(
+                            "`{}` is not a predicate, use either `all`, `any` or `not`",
+                            other
+                        ),
+                    )),
+                }
+            }
+            Some('=') => {
+                self.position += 1;
+                self.skip_whitespace();
+                let value = self.string()?;
+                Ok(CfgExpression::KeyValue(
+                    identifier.to_owned(),
+                    value.to_owned(),
+                ))
+            }
+            _ => Ok(CfgExpression::Name(identifier.to_owned())),
+        }
+    }
+
+    fn list(&mut self) -> Result<Vec<CfgExpression>, String> {
+        let mut expressions = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(')') {
+                self.position += 1;
+                return Ok(expressions);
+            }
+
+            expressions.push(self.expression()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(')') => {}
+                Some(c) => {
+                    return Err(self.error(&format!("expected `,` or `)` but found `{}`", c)))
+                }
+                None => return Err(self.error("expected `)` but found end of input")),
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, String> {
+        let remaining = &self.value[self.position..];
+
+        match remaining.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+            Some(c) => return Err(self.error(&format!("expected identifier but found `{}`", c))),
+            None => return Err(self.error("expected identifier but found end of input")),
+        }
+
+        let length = remaining
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(remaining.len());
+
+        self.position += length;
+        Ok(&remaining[..length])
+    }
+
+    fn string(&mut self) -> Result<&'a str, String> {
+        match self.peek() {
+            Some('"') => self.position += 1,
+            Some(c) => return Err(self.error(&format!("expected string but found `{}`", c))),
+            None => return Err(self.error("expected string but found end of input")),
+        }
+
+        let remaining = &self.value[self.position..];
+        match remaining.find('"') {
+            Some(length) => {
+                self.position += length + 1;
+                Ok(&remaining[..length])
+            }
+            None => Err(self.error("unterminated string")),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.value[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = &self.value[self.position..];
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn error(&self, reason: &str) -> String {
+        self.error_at(self.position, reason)
+    }
+
+    fn error_at(&self, position: usize, reason: &str) -> String {
+        format!(
+            "{} is not a valid cfg expression: {} at position {}.",
+            self.source, reason, position
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate() {
+        let is_set = |key: &str, value: Option<&str>| {
+            matches!((key, value), ("unix", None) | ("target_os", Some("linux")))
+        };
+
+        let test_cases = vec![
+            ("unix", true),
+            ("windows", false),
+            (r#"target_os = "linux""#, true),
+            (r#"target_os = "macos""#, false),
+            (r#"all(unix, not(target_os = "macos"))"#, true),
+            (r#"all(unix, target_os = "macos")"#, false),
+            (r#"any(windows, target_os = "linux")"#, true),
+            ("any(windows)", false),
+            ("all()", true),
+            ("any()", false),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                CfgExpression::parse(value).unwrap().evaluate(&is_set),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(CfgExpression::All(vec![
+                CfgExpression::Name("unix".to_owned()),
+                CfgExpression::Not(Box::new(CfgExpression::KeyValue(
+                    "target_os".to_owned(),
+                    "macos".to_owned()
+                ))),
+            ])),
+            CfgExpression::parse(r#"all(unix, not(target_os = "macos"))"#)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        let test_cases = vec![
+            (
+                "",
+                "expected identifier but found end of input at position 0",
+            ),
+            (
+                "all(unix",
+                "expected `)` but found end of input at position 8",
+            ),
+            (
+                "all(unix windows)",
+                "expected `,` or `)` but found `w` at position 9",
+            ),
+            ("all(,)", "expected identifier but found `,` at position 4"),
+            (
+                "target_os = macos",
+                "expected string but found `m` at position 12",
+            ),
+            (
+                r#"target_os = "macos"#,
+                "unterminated string at position 13",
+            ),
+            (
+                "not()",
+                "`not` expects exactly one expression but found 0 at position 0",
+            ),
+            (
+                "some(unix)",
+                "`some` is not a predicate, use either `all`, `any` or `not` at position 0",
+            ),
+            ("unix)", "unexpected `)` at position 4"),
+        ];
+
+        for (value, reason) in test_cases {
+            assert_eq!(
+                Err(format!(
+                    "{} is not a valid cfg expression: {}.",
+                    value, reason
+                )),
+                CfgExpression::parse(value)
+            );
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        let test_cases = vec![
+            ("unix", "unix"),
+            (r#"target_os="macos""#, r#"target_os = "macos""#),
+            (
+                r#"all( unix ,not( target_os = "macos" ), )"#,
+                r#"all(unix, not(target_os = "macos"))"#,
+            ),
+            ("any()", "any()"),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(expected, CfgExpression::parse(value).unwrap().to_string());
+        }
+    }
+}
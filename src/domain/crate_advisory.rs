@@ -0,0 +1,223 @@
+use crate::domain::{CrateAdvisorySeverity, CrateName, CrateRequirement, CrateVersion};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateAdvisory {
+    pub id: String,
+    pub name: CrateName,
+    /// Severity rated from the CVSS vector, unknown when the advisory has none or it cannot be
+    /// rated.
+    pub severity: Option<CrateAdvisorySeverity>,
+    /// Kind of informational advisory, such as `unmaintained`, unknown for vulnerabilities.
+    pub informational: Option<String>,
+    pub patched: Vec<CrateRequirement>,
+    pub unaffected: Vec<CrateRequirement>,
+    /// Whether the advisory was withdrawn, in which case it affects no version.
+    pub withdrawn: bool,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryToml {
+    advisory: AdvisoryMetadataToml,
+    #[serde(default)]
+    versions: AdvisoryVersionsToml,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryMetadataToml {
+    id: String,
+    package: String,
+    cvss: Option<String>,
+    informational: Option<String>,
+    withdrawn: Option<toml::Value>,
+}
+
+#[derive(Default, Deserialize)]
+struct AdvisoryVersionsToml {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+impl CrateAdvisory {
+    /// Versions are affected unless a patched or unaffected requirement matches them.
+    pub fn affects(&self, version: &CrateVersion) -> bool {
+        !self.withdrawn
+            && !self
+                .patched
+                .iter()
+                .chain(&self.unaffected)
+                .any(|requirement| requirement.matches(version))
+    }
+
+    /// Parses an advisory of the RustSec advisory database, either a Markdown file whose front
+    /// matter is a fenced TOML block or a TOML file.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let front_matter = match value.trim_start().strip_prefix("```toml") {
+            Some(value) => value
+                .find("```")
+                .map(|index| &value[..index])
+                .ok_or("Advisory is not valid: the TOML front matter is not closed.")?,
+            None => value,
+        };
+
+        let advisory = toml::from_str::<AdvisoryToml>(front_matter)
+            .map_err(|e| format!("Advisory is not valid: {}.", e))?;
+
+        let requirements = |values: &[String]| {
+            values
+                .iter()
+                .map(|value| CrateRequirement::parse(value))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(CrateAdvisory {
+            name: CrateName::parse(&advisory.advisory.package)?,
+            severity: advisory
+                .advisory
+                .cvss
+                .as_deref()
+                .and_then(|cvss| CrateAdvisorySeverity::from_cvss(cvss).ok()),
+            informational: advisory.advisory.informational,
+            patched: requirements(&advisory.versions.patched)?,
+            unaffected: requirements(&advisory.versions.unaffected)?,
+            withdrawn: advisory.advisory.withdrawn.is_some(),
+            id: advisory.advisory.id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let advisory = CrateAdvisory::parse(
+            r#"```toml
+[advisory]
+id = "RUSTSEC-2021-0003"
+package = "smallvec"
+date = "2021-01-08"
+url = "https://github.com/servo/rust-smallvec/issues/252"
+categories = ["memory-corruption"]
+keywords = ["buffer-overflow", "heap-overflow"]
+cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+
+[versions]
+patched = [">= 0.6.14, < 1.0.0", ">= 1.6.1"]
+unaffected = ["< 0.6.3"]
+```
+
+# Buffer overflow in SmallVec::insert_many
+
+A bug in the SmallVec::insert_many method caused it to allocate a buffer that was smaller
+than needed.
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CrateAdvisory {
+                id: "RUSTSEC-2021-0003".to_owned(),
+                name: CrateName::parse("smallvec").unwrap(),
+                severity: Some(CrateAdvisorySeverity::Critical),
+                informational: None,
+                patched: vec![
+                    CrateRequirement::parse(">= 0.6.14, < 1.0.0").unwrap(),
+                    CrateRequirement::parse(">= 1.6.1").unwrap(),
+                ],
+                unaffected: vec![CrateRequirement::parse("< 0.6.3").unwrap()],
+                withdrawn: false,
+            },
+            advisory
+        );
+    }
+
+    #[test]
+    fn parse_toml() {
+        let advisory = CrateAdvisory::parse(
+            r#"
+[advisory]
+id = "RUSTSEC-2020-0036"
+package = "failure"
+informational = "unmaintained"
+withdrawn = "2020-06-01"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(None, advisory.severity);
+        assert_eq!(Some("unmaintained".to_owned()), advisory.informational);
+        assert!(advisory.patched.is_empty());
+        assert!(advisory.withdrawn);
+    }
+
+    #[test]
+    fn parse_keeps_advisories_that_cannot_be_rated() {
+        let advisory = CrateAdvisory::parse(
+            r#"
+[advisory]
+id = "RUSTSEC-2021-0001"
+package = "a"
+cvss = "CVSS:4.0/AV:N"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(None, advisory.severity);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_advisories() {
+        let test_cases = vec![
+            ("```toml\n[advisory]\nid = \"RUSTSEC-2021-0001\"", "Advisory is not valid: the TOML front matter is not closed."),
+            ("[advisory]\nid = \"RUSTSEC-2021-0001\"", "Advisory is not valid: "),
+            (
+                "[advisory]\nid = \"RUSTSEC-2021-0001\"\npackage = \"a\"\n[versions]\npatched = [\"1.0.0.0\"]",
+                "1.0.0.0 is not a valid version requirement: ",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            let result = CrateAdvisory::parse(value).unwrap_err();
+            assert!(result.starts_with(expected), "{}", result);
+        }
+    }
+
+    #[test]
+    fn affects() {
+        let advisory = CrateAdvisory::parse(
+            r#"
+[advisory]
+id = "RUSTSEC-2021-0003"
+package = "smallvec"
+
+[versions]
+patched = [">= 0.6.14, < 1.0.0", ">= 1.6.1"]
+unaffected = ["< 0.6.3"]
+"#,
+        )
+        .unwrap();
+
+        let test_cases = vec![
+            ("0.6.2", false),
+            ("0.6.3", true),
+            ("0.6.14", false),
+            ("1.6.0", true),
+            ("1.6.1", false),
+        ];
+
+        for (version, expected) in test_cases {
+            let version = CrateVersion::parse(version).unwrap();
+            assert_eq!(expected, advisory.affects(&version), "{:?}", version);
+        }
+
+        let withdrawn = CrateAdvisory {
+            withdrawn: true,
+            ..advisory
+        };
+        assert!(!withdrawn.affects(&CrateVersion::parse("1.6.0").unwrap()));
+    }
+}
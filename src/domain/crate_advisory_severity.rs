@@ -0,0 +1,209 @@
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrateAdvisorySeverity {
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl CrateAdvisorySeverity {
+    pub fn as_str(&self) -> &str {
+        match &self {
+            CrateAdvisorySeverity::None => "none",
+            CrateAdvisorySeverity::Low => "low",
+            CrateAdvisorySeverity::Medium => "medium",
+            CrateAdvisorySeverity::High => "high",
+            CrateAdvisorySeverity::Critical => "critical",
+        }
+    }
+
+    /// Rates a CVSS v3 vector by its base score, the way the RustSec advisory database does.
+    pub fn from_cvss(vector: &str) -> Result<Self, String> {
+        let error = || format!("{} is not a valid CVSS v3 vector.", vector);
+
+        let mut metrics = vector.split('/');
+        match metrics.next() {
+            Some("CVSS:3.0") | Some("CVSS:3.1") => {}
+            _ => return Err(error()),
+        }
+
+        let mut values = [None; 8];
+        for metric in metrics {
+            let (name, value) = metric
+                .find(':')
+                .map(|index| (&metric[..index], &metric[index + 1..]))
+                .ok_or_else(error)?;
+
+            let index = ["AV", "AC", "PR", "UI", "S", "C", "I", "A"]
+                .iter()
+                .position(|other| *other == name)
+                .ok_or_else(error)?;
+
+            values[index] = Some(value);
+        }
+
+        let value = |index: usize| values[index].ok_or_else(error);
+
+        let changed = match value(4)? {
+            "U" => false,
+            "C" => true,
+            _ => return Err(error()),
+        };
+
+        let weight = |index: usize, weights: &[(&str, f64)]| {
+            let value = value(index)?;
+            weights
+                .iter()
+                .find(|(other, _)| *other == value)
+                .map(|(_, weight)| *weight)
+                .ok_or_else(error)
+        };
+
+        let attack_vector = weight(0, &[("N", 0.85), ("A", 0.62), ("L", 0.55), ("P", 0.2)])?;
+        let attack_complexity = weight(1, &[("L", 0.77), ("H", 0.44)])?;
+        let privileges_required = if changed {
+            weight(2, &[("N", 0.85), ("L", 0.68), ("H", 0.5)])?
+        } else {
+            weight(2, &[("N", 0.85), ("L", 0.62), ("H", 0.27)])?
+        };
+        let user_interaction = weight(3, &[("N", 0.85), ("R", 0.62)])?;
+
+        let impact = [5, 6, 7]
+            .iter()
+            .map(|index| weight(*index, &[("H", 0.56), ("L", 0.22), ("N", 0.0)]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let impact_sub_score = 1.0 - impact.iter().map(|impact| 1.0 - impact).product::<f64>();
+        let impact = if changed {
+            7.52 * (impact_sub_score - 0.029) - 3.25 * (impact_sub_score - 0.02).powi(15)
+        } else {
+            6.42 * impact_sub_score
+        };
+        let exploitability =
+            8.22 * attack_vector * attack_complexity * privileges_required * user_interaction;
+
+        let score = if impact <= 0.0 {
+            0.0
+        } else if changed {
+            round_up((1.08 * (impact + exploitability)).min(10.0))
+        } else {
+            round_up((impact + exploitability).min(10.0))
+        };
+
+        Ok(if score == 0.0 {
+            CrateAdvisorySeverity::None
+        } else if score < 4.0 {
+            CrateAdvisorySeverity::Low
+        } else if score < 7.0 {
+            CrateAdvisorySeverity::Medium
+        } else if score < 9.0 {
+            CrateAdvisorySeverity::High
+        } else {
+            CrateAdvisorySeverity::Critical
+        })
+    }
+}
+
+/// Rounds up to one decimal place, working around floating point errors as CVSS v3.1 specifies.
+fn round_up(value: f64) -> f64 {
+    let value = (value * 100_000.0).round() as i64;
+    if value % 10_000 == 0 {
+        value as f64 / 100_000.0
+    } else {
+        ((value / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+impl TryFrom<&str> for CrateAdvisorySeverity {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "none" => Ok(Self::None),
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            other => Err(format!(
+                "{} is not a supported severity. Use either `none`, `low`, `medium`, `high` or `critical`.",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str() {
+        for value in &["none", "low", "medium", "high", "critical"] {
+            let result = CrateAdvisorySeverity::try_from(*value).unwrap();
+            assert_eq!(*value, result.as_str());
+        }
+    }
+
+    #[test]
+    fn from_cvss() {
+        let test_cases = vec![
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                CrateAdvisorySeverity::Critical,
+            ),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:H",
+                CrateAdvisorySeverity::High,
+            ),
+            (
+                "CVSS:3.0/AV:N/AC:H/PR:N/UI:R/S:C/C:L/I:L/A:N",
+                CrateAdvisorySeverity::Medium,
+            ),
+            (
+                "CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N",
+                CrateAdvisorySeverity::Low,
+            ),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N",
+                CrateAdvisorySeverity::None,
+            ),
+        ];
+
+        for (vector, expected) in test_cases {
+            assert_eq!(
+                expected,
+                CrateAdvisorySeverity::from_cvss(vector).unwrap(),
+                "{}",
+                vector
+            );
+        }
+    }
+
+    #[test]
+    fn from_cvss_rejects_invalid_vectors() {
+        let test_cases = vec![
+            "CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H",
+            "CVSS:3.1/AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E",
+        ];
+
+        for vector in test_cases {
+            assert_eq!(
+                format!("{} is not a valid CVSS v3 vector.", vector),
+                CrateAdvisorySeverity::from_cvss(vector).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_rejects_unsupported_values() {
+        assert_eq!(
+            "severe is not a supported severity. Use either `none`, `low`, `medium`, `high` or `critical`.",
+            CrateAdvisorySeverity::try_from("severe").unwrap_err()
+        );
+    }
+}
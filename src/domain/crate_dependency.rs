@@ -1,8 +1,12 @@
-use crate::domain::{CrateDependencyType, CrateName, CrateRequirement};
+use crate::domain::{CrateDependencyType, CrateName, CrateRequirement, CrateTarget};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CrateDependency {
     pub name: CrateName,
     pub requirement: CrateRequirement,
     pub type_: CrateDependencyType,
+    pub optional: bool,
+    pub default_features: bool,
+    pub features: Vec<String>,
+    pub target: Option<CrateTarget>,
 }
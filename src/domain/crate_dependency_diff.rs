@@ -0,0 +1,155 @@
+use crate::domain::CrateDependency;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CrateDependencyDiff {
+    pub added: Vec<CrateDependency>,
+    pub removed: Vec<CrateDependency>,
+    pub changed: Vec<CrateDependencyChange>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateDependencyChange {
+    pub from: CrateDependency,
+    pub to: CrateDependency,
+}
+
+impl CrateDependencyDiff {
+    /// Dependencies are matched by name and target. A crate declared with several kinds is first
+    /// matched to the declaration of the same kind, so only the declarations left over are
+    /// reported as changing kind.
+    pub fn new(from: &[CrateDependency], to: &[CrateDependency]) -> Self {
+        let mut diff = Self::default();
+        let mut remaining = to.iter().map(Some).collect::<Vec<_>>();
+        let mut unmatched = Vec::new();
+
+        for dependency in from {
+            match take(&mut remaining, |other| {
+                is_same_crate(dependency, other) && dependency.type_ == other.type_
+            }) {
+                Some(other) => diff.push_change(dependency, other),
+                None => unmatched.push(dependency),
+            }
+        }
+
+        for dependency in unmatched {
+            match take(&mut remaining, |other| is_same_crate(dependency, other)) {
+                Some(other) => diff.push_change(dependency, other),
+                None => diff.removed.push(dependency.clone()),
+            }
+        }
+
+        diff.added = remaining.into_iter().flatten().cloned().collect();
+
+        diff
+    }
+
+    fn push_change(&mut self, from: &CrateDependency, to: &CrateDependency) {
+        if from != to {
+            self.changed.push(CrateDependencyChange {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+    }
+}
+
+fn is_same_crate(a: &CrateDependency, b: &CrateDependency) -> bool {
+    a.name == b.name && a.target == b.target
+}
+
+fn take<'a>(
+    dependencies: &mut [Option<&'a CrateDependency>],
+    predicate: impl Fn(&CrateDependency) -> bool,
+) -> Option<&'a CrateDependency> {
+    dependencies
+        .iter_mut()
+        .find(|dependency| matches!(dependency, Some(dependency) if predicate(dependency)))
+        .and_then(Option::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{dependency, target};
+    use crate::domain::CrateDependencyType;
+
+    #[test]
+    fn new_returns_empty_when_dependencies_are_equal() {
+        let dependencies = vec![
+            dependency("a", "^1.0", CrateDependencyType::Normal),
+            dependency("b", "^1.0", CrateDependencyType::Dev),
+        ];
+
+        assert_eq!(
+            CrateDependencyDiff::default(),
+            CrateDependencyDiff::new(&dependencies, &dependencies)
+        );
+    }
+
+    #[test]
+    fn new_returns_added_removed_and_changed_dependencies() {
+        let from = vec![
+            dependency("a", "^1.0", CrateDependencyType::Normal),
+            dependency("b", "^1.0", CrateDependencyType::Normal),
+            dependency("c", "^1.0", CrateDependencyType::Normal),
+            CrateDependency {
+                target: Some(target("cfg(unix)")),
+                ..dependency("d", "^1.0", CrateDependencyType::Normal)
+            },
+        ];
+        let to = vec![
+            dependency("a", "^1.2", CrateDependencyType::Normal),
+            dependency("c", "^1.0", CrateDependencyType::Dev),
+            dependency("d", "^1.0", CrateDependencyType::Normal),
+            dependency("e", "^1.0", CrateDependencyType::Build),
+        ];
+
+        assert_eq!(
+            CrateDependencyDiff {
+                added: vec![
+                    dependency("d", "^1.0", CrateDependencyType::Normal),
+                    dependency("e", "^1.0", CrateDependencyType::Build),
+                ],
+                removed: vec![
+                    dependency("b", "^1.0", CrateDependencyType::Normal),
+                    from[3].clone(),
+                ],
+                changed: vec![
+                    CrateDependencyChange {
+                        from: from[0].clone(),
+                        to: to[0].clone(),
+                    },
+                    CrateDependencyChange {
+                        from: from[2].clone(),
+                        to: to[1].clone(),
+                    },
+                ],
+            },
+            CrateDependencyDiff::new(&from, &to)
+        );
+    }
+
+    #[test]
+    fn new_matches_dependencies_of_the_same_kind_first() {
+        let from = vec![
+            dependency("a", "^1.0", CrateDependencyType::Normal),
+            dependency("a", "^1.0", CrateDependencyType::Dev),
+        ];
+        let to = vec![
+            dependency("a", "^1.0", CrateDependencyType::Dev),
+            dependency("a", "^1.0", CrateDependencyType::Build),
+        ];
+
+        assert_eq!(
+            CrateDependencyDiff {
+                added: vec![],
+                removed: vec![],
+                changed: vec![CrateDependencyChange {
+                    from: from[0].clone(),
+                    to: to[1].clone(),
+                }],
+            },
+            CrateDependencyDiff::new(&from, &to)
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::{CrateDependency, CrateName, CrateVersion};
+
+/// A crate version that depends on another crate through `dependency`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateDependent {
+    pub name: CrateName,
+    pub version: CrateVersion,
+    pub dependency: CrateDependency,
+}
@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+/// Where reverse dependencies are looked up, either crates.io or the crates cached by this service.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CrateDependentSource {
+    Cache,
+    CratesIo,
+}
+
+impl CrateDependentSource {
+    pub fn as_str(&self) -> &str {
+        match &self {
+            CrateDependentSource::Cache => "cache",
+            CrateDependentSource::CratesIo => "crates_io",
+        }
+    }
+}
+
+impl TryFrom<&str> for CrateDependentSource {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "cache" => Ok(Self::Cache),
+            "crates_io" => Ok(Self::CratesIo),
+            other => Err(format!(
+                "{} is not a supported source. Use either `cache` or `crates_io`.",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+
+    #[test]
+    fn as_str() {
+        assert_eq!("cache", CrateDependentSource::Cache.as_str());
+        assert_eq!("crates_io", CrateDependentSource::CratesIo.as_str());
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            Ok(CrateDependentSource::Cache),
+            CrateDependentSource::try_from("cache")
+        );
+        assert_eq!(
+            Ok(CrateDependentSource::CratesIo),
+            CrateDependentSource::try_from("crates_io")
+        );
+
+        let other = Faker.fake::<String>();
+        assert_eq!(
+            Err(format!(
+                "{} is not a supported source. Use either `cache` or `crates_io`.",
+                other
+            )),
+            CrateDependentSource::try_from(other.as_str())
+        );
+    }
+}
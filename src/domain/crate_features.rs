@@ -0,0 +1,285 @@
+use crate::domain::CrateDependency;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CrateFeatures(BTreeMap<String, Vec<String>>);
+
+#[derive(Debug, PartialEq)]
+pub struct FeatureActivation {
+    pub enabled: BTreeSet<String>,
+    activated: BTreeSet<String>,
+    dependency_features: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CrateFeatures {
+    pub fn new(features: BTreeMap<String, Vec<String>>) -> Self {
+        Self(features)
+    }
+
+    pub fn as_map(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.0
+    }
+
+    /// Activates the `requested` features, including `default` when default features are
+    /// enabled, following Cargo's rules for `dep:name`, `name/feature` and `name?/feature`.
+    pub fn activate(
+        &self,
+        dependencies: &[CrateDependency],
+        requested: &BTreeSet<String>,
+    ) -> FeatureActivation {
+        let explicit = self
+            .0
+            .values()
+            .flatten()
+            .filter_map(|value| value.strip_prefix("dep:"))
+            .collect::<BTreeSet<_>>();
+
+        let is_optional_dependency = |name: &str| {
+            dependencies
+                .iter()
+                .any(|dependency| dependency.optional && dependency.name.as_str() == name)
+        };
+
+        let mut enabled = BTreeSet::new();
+        let mut activated = BTreeSet::new();
+        let mut weak_features = Vec::new();
+        let mut dependency_features: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+        let mut queue = requested.iter().cloned().collect::<VecDeque<_>>();
+
+        while let Some(feature) = queue.pop_front() {
+            if enabled.contains(&feature) {
+                continue;
+            }
+
+            let values = match self.0.get(&feature) {
+                Some(values) => values,
+                None => {
+                    if !explicit.contains(feature.as_str()) && is_optional_dependency(&feature) {
+                        activated.insert(feature.clone());
+                        enabled.insert(feature);
+                    }
+                    continue;
+                }
+            };
+
+            enabled.insert(feature);
+
+            for value in values {
+                if let Some(name) = value.strip_prefix("dep:") {
+                    activated.insert(name.to_owned());
+                } else if let Some((name, dependency_feature)) = split_once(value, '/') {
+                    match name.strip_suffix('?') {
+                        Some(name) => {
+                            weak_features.push((name.to_owned(), dependency_feature.to_owned()))
+                        }
+                        None => {
+                            if is_optional_dependency(name) {
+                                activated.insert(name.to_owned());
+                                if !explicit.contains(name) {
+                                    queue.push_back(name.to_owned());
+                                }
+                            }
+                            dependency_features
+                                .entry(name.to_owned())
+                                .or_default()
+                                .insert(dependency_feature.to_owned());
+                        }
+                    }
+                } else {
+                    queue.push_back(value.to_owned());
+                }
+            }
+        }
+
+        for (name, dependency_feature) in weak_features {
+            let is_activated = activated.contains(&name)
+                || dependencies
+                    .iter()
+                    .any(|dependency| !dependency.optional && dependency.name.as_str() == name);
+
+            if is_activated {
+                dependency_features
+                    .entry(name)
+                    .or_default()
+                    .insert(dependency_feature);
+            }
+        }
+
+        FeatureActivation {
+            enabled,
+            activated,
+            dependency_features,
+        }
+    }
+}
+
+impl FeatureActivation {
+    pub fn is_activated(&self, dependency: &CrateDependency) -> bool {
+        !dependency.optional || self.activated.contains(dependency.name.as_str())
+    }
+
+    /// Returns the features enabled on `dependency`, including `default` when its default
+    /// features are enabled.
+    pub fn features_for(&self, dependency: &CrateDependency) -> BTreeSet<String> {
+        let mut features = dependency.features.iter().cloned().collect::<BTreeSet<_>>();
+
+        if dependency.default_features {
+            features.insert("default".to_owned());
+        }
+
+        if let Some(dependency_features) = self.dependency_features.get(dependency.name.as_str()) {
+            features.extend(dependency_features.iter().cloned());
+        }
+
+        features
+    }
+}
+
+fn split_once(value: &str, delimiter: char) -> Option<(&str, &str)> {
+    let index = value.find(delimiter)?;
+    Some((&value[..index], &value[index + delimiter.len_utf8()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{CrateDependencyType, CrateName, CrateRequirement};
+
+    #[test]
+    fn activate_enables_default_features() {
+        let activation = features(&[("default", &["std"]), ("std", &[]), ("alloc", &[])])
+            .activate(&[], &requested(&["default"]));
+
+        assert_eq!(requested(&["default", "std"]), activation.enabled);
+    }
+
+    #[test]
+    fn activate_ignores_missing_default_feature() {
+        let activation = features(&[("std", &[])]).activate(&[], &requested(&["default"]));
+
+        assert!(activation.enabled.is_empty());
+    }
+
+    #[test]
+    fn activate_activates_optional_dependencies_through_implicit_features() {
+        let dependencies = [optional("serde"), optional("log")];
+
+        let activation =
+            features(&[("serde1", &["serde"])]).activate(&dependencies, &requested(&["serde1"]));
+
+        assert_eq!(requested(&["serde", "serde1"]), activation.enabled);
+        assert!(activation.is_activated(&dependencies[0]));
+        assert!(!activation.is_activated(&dependencies[1]));
+    }
+
+    #[test]
+    fn activate_activates_optional_dependencies_through_dep_syntax() {
+        let dependencies = [optional("serde")];
+
+        let activation = features(&[("serde1", &["dep:serde"])])
+            .activate(&dependencies, &requested(&["serde1"]));
+
+        assert_eq!(requested(&["serde1"]), activation.enabled);
+        assert!(activation.is_activated(&dependencies[0]));
+    }
+
+    #[test]
+    fn activate_does_not_create_implicit_features_for_dep_syntax() {
+        let dependencies = [optional("serde")];
+
+        let activation =
+            features(&[("serde1", &["dep:serde"])]).activate(&dependencies, &requested(&["serde"]));
+
+        assert!(activation.enabled.is_empty());
+        assert!(!activation.is_activated(&dependencies[0]));
+    }
+
+    #[test]
+    fn activate_enables_dependency_features() {
+        let dependencies = [required("rand_core"), optional("rand_chacha")];
+
+        let activation = features(&[("std", &["rand_core/std", "rand_chacha/std"])])
+            .activate(&dependencies, &requested(&["std"]));
+
+        assert_eq!(requested(&["rand_chacha", "std"]), activation.enabled);
+        assert!(activation.is_activated(&dependencies[1]));
+        assert_eq!(
+            requested(&["default", "std"]),
+            activation.features_for(&dependencies[0])
+        );
+        assert_eq!(
+            requested(&["default", "std"]),
+            activation.features_for(&dependencies[1])
+        );
+    }
+
+    #[test]
+    fn activate_enables_weak_dependency_features_only_when_activated() {
+        let dependencies = [optional("serde"), optional("log")];
+
+        let activation = features(&[("std", &["serde?/std", "log?/std"]), ("logging", &["log"])])
+            .activate(&dependencies, &requested(&["std", "logging"]));
+
+        assert!(!activation.is_activated(&dependencies[0]));
+        assert!(activation.is_activated(&dependencies[1]));
+        assert_eq!(
+            requested(&["default"]),
+            activation.features_for(&dependencies[0])
+        );
+        assert_eq!(
+            requested(&["default", "std"]),
+            activation.features_for(&dependencies[1])
+        );
+    }
+
+    #[test]
+    fn features_for_includes_declared_features() {
+        let dependency = CrateDependency {
+            default_features: false,
+            features: vec!["derive".to_owned()],
+            ..required("serde")
+        };
+
+        let activation = CrateFeatures::default().activate(&[], &requested(&[]));
+
+        assert_eq!(requested(&["derive"]), activation.features_for(&dependency));
+    }
+
+    fn features(values: &[(&str, &[&str])]) -> CrateFeatures {
+        CrateFeatures::new(
+            values
+                .iter()
+                .map(|(feature, values)| {
+                    (
+                        (*feature).to_owned(),
+                        values.iter().map(|value| (*value).to_owned()).collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn optional(name: &str) -> CrateDependency {
+        CrateDependency {
+            optional: true,
+            ..required(name)
+        }
+    }
+
+    fn required(name: &str) -> CrateDependency {
+        CrateDependency {
+            name: CrateName::parse(name).unwrap(),
+            requirement: CrateRequirement::parse("*").unwrap(),
+            type_: CrateDependencyType::Normal,
+            optional: false,
+            default_features: true,
+            features: vec![],
+            target: None,
+        }
+    }
+
+    fn requested(values: &[&str]) -> BTreeSet<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+}
@@ -0,0 +1,395 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// SPDX license expression, normalized so expressions that only differ in the order, repetition
+/// or grouping of their operands are equal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrateLicense {
+    All(Vec<CrateLicense>),
+    Any(Vec<CrateLicense>),
+    License {
+        id: String,
+        exception: Option<String>,
+    },
+}
+
+impl CrateLicense {
+    /// Evaluates the expression, using `is_allowed` to look up whether a license identifier is
+    /// allowed. Exceptions only ever grant additional permissions, so they are not looked up.
+    pub fn evaluate<F>(&self, is_allowed: &F) -> bool
+    where
+        F: Fn(&str) -> bool,
+    {
+        match self {
+            CrateLicense::All(expressions) => expressions.iter().all(|e| e.evaluate(is_allowed)),
+            CrateLicense::Any(expressions) => expressions.iter().any(|e| e.evaluate(is_allowed)),
+            CrateLicense::License { id, .. } => is_allowed(id),
+        }
+    }
+
+    /// Returns the license identifiers the expression refers to, in alphabetical order.
+    pub fn licenses(&self) -> Vec<&str> {
+        let mut licenses = BTreeSet::new();
+        self.collect_licenses(&mut licenses);
+        licenses.into_iter().collect()
+    }
+
+    fn collect_licenses<'a>(&'a self, licenses: &mut BTreeSet<&'a str>) {
+        match self {
+            CrateLicense::All(expressions) | CrateLicense::Any(expressions) => {
+                for expression in expressions {
+                    expression.collect_licenses(licenses);
+                }
+            }
+            CrateLicense::License { id, .. } => {
+                licenses.insert(id);
+            }
+        }
+    }
+
+    /// Parses an SPDX license expression. The `/` separator of older crates is read as `OR`,
+    /// which is how crates.io interprets it.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut parser = Parser { value, position: 0 };
+
+        let expression = parser.any()?;
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            None => Ok(expression),
+            Some(c) => Err(parser.error(&format!("unexpected `{}`", c))),
+        }
+    }
+
+    /// Flattens nested operands of the same operator, then sorts and deduplicates them.
+    fn combine(any: bool, operands: Vec<CrateLicense>) -> Self {
+        let mut flattened = Vec::with_capacity(operands.len());
+        for operand in operands {
+            match operand {
+                CrateLicense::Any(expressions) if any => flattened.extend(expressions),
+                CrateLicense::All(expressions) if !any => flattened.extend(expressions),
+                other => flattened.push(other),
+            }
+        }
+
+        let mut keyed = flattened
+            .into_iter()
+            .map(|expression| (expression.to_string(), expression))
+            .collect::<Vec<_>>();
+        keyed.sort_by(|(a, _), (b, _)| compare(a, b));
+        keyed.dedup_by(|(a, _), (b, _)| a == b);
+
+        let mut expressions = keyed
+            .into_iter()
+            .map(|(_, expression)| expression)
+            .collect::<Vec<_>>();
+
+        match (expressions.len(), any) {
+            (1, _) => expressions.remove(0),
+            (_, true) => CrateLicense::Any(expressions),
+            (_, false) => CrateLicense::All(expressions),
+        }
+    }
+}
+
+/// License identifiers are case insensitive, so they are sorted ignoring case first.
+fn compare(a: &str, b: &str) -> Ordering {
+    a.to_lowercase()
+        .cmp(&b.to_lowercase())
+        .then_with(|| a.cmp(b))
+}
+
+impl fmt::Display for CrateLicense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, operator: &str, expressions: &[CrateLicense]| {
+            for (i, expression) in expressions.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", operator)?;
+                }
+                match expression {
+                    CrateLicense::License { .. } => write!(f, "{}", expression)?,
+                    _ => write!(f, "({})", expression)?,
+                }
+            }
+            Ok(())
+        };
+
+        match self {
+            CrateLicense::All(expressions) => list(f, "AND", expressions),
+            CrateLicense::Any(expressions) => list(f, "OR", expressions),
+            CrateLicense::License {
+                id,
+                exception: Some(exception),
+            } => write!(f, "{} WITH {}", id, exception),
+            CrateLicense::License {
+                id,
+                exception: None,
+            } => write!(f, "{}", id),
+        }
+    }
+}
+
+struct Parser<'a> {
+    value: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn any(&mut self) -> Result<CrateLicense, String> {
+        let mut operands = vec![self.all()?];
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('/') {
+                self.position += 1;
+            } else if !self.operator("OR") {
+                break;
+            }
+            operands.push(self.all()?);
+        }
+
+        Ok(CrateLicense::combine(true, operands))
+    }
+
+    fn all(&mut self) -> Result<CrateLicense, String> {
+        let mut operands = vec![self.license()?];
+
+        while self.operator("AND") {
+            operands.push(self.license()?);
+        }
+
+        Ok(CrateLicense::combine(false, operands))
+    }
+
+    fn license(&mut self) -> Result<CrateLicense, String> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('(') {
+            self.position += 1;
+            let expression = self.any()?;
+            self.skip_whitespace();
+
+            return match self.peek() {
+                Some(')') => {
+                    self.position += 1;
+                    Ok(expression)
+                }
+                Some(c) => Err(self.error(&format!("expected `)` but found `{}`", c))),
+                None => Err(self.error("expected `)` but found end of input")),
+            };
+        }
+
+        let id = self.identifier("license")?;
+
+        let exception = if self.operator("WITH") {
+            self.skip_whitespace();
+            Some(self.identifier("exception")?.to_owned())
+        } else {
+            None
+        };
+
+        Ok(CrateLicense::License {
+            id: id.to_owned(),
+            exception,
+        })
+    }
+
+    /// Consumes the operator when it comes next, in any case.
+    fn operator(&mut self, operator: &str) -> bool {
+        self.skip_whitespace();
+        let start = self.position;
+
+        let word = self.word();
+        if word.eq_ignore_ascii_case(operator) {
+            true
+        } else {
+            self.position = start;
+            false
+        }
+    }
+
+    fn identifier(&mut self, expected: &str) -> Result<&'a str, String> {
+        let start = self.position;
+        let word = self.word();
+
+        if word.is_empty() || is_operator(word) {
+            self.position = start;
+            let found = match self.peek() {
+                None => "end of input".to_owned(),
+                Some(_) if !word.is_empty() => format!("`{}`", word),
+                Some(c) => format!("`{}`", c),
+            };
+            return Err(self.error(&format!("expected {} but found {}", expected, found)));
+        }
+
+        Ok(word)
+    }
+
+    fn word(&mut self) -> &'a str {
+        let remaining = &self.value[self.position..];
+        let length = remaining
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+' | ':')))
+            .unwrap_or(remaining.len());
+
+        self.position += length;
+        &remaining[..length]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.value[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = &self.value[self.position..];
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn error(&self, reason: &str) -> String {
+        format!(
+            "{} is not a valid license expression: {} at position {}.",
+            self.value, reason, self.position
+        )
+    }
+}
+
+fn is_operator(word: &str) -> bool {
+    ["AND", "OR", "WITH"]
+        .iter()
+        .any(|operator| word.eq_ignore_ascii_case(operator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate() {
+        let is_allowed = |id: &str| matches!(id, "MIT" | "Apache-2.0");
+
+        let test_cases = vec![
+            ("MIT", true),
+            ("GPL-3.0", false),
+            ("MIT OR GPL-3.0", true),
+            ("MIT AND GPL-3.0", false),
+            ("(MIT OR GPL-3.0) AND Apache-2.0", true),
+            ("Apache-2.0 WITH LLVM-exception", true),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                CrateLicense::parse(value).unwrap().evaluate(&is_allowed),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(CrateLicense::Any(vec![
+                CrateLicense::License {
+                    id: "Apache-2.0".to_owned(),
+                    exception: Some("LLVM-exception".to_owned()),
+                },
+                CrateLicense::All(vec![
+                    CrateLicense::License {
+                        id: "MIT".to_owned(),
+                        exception: None,
+                    },
+                    CrateLicense::License {
+                        id: "Unicode-DFS-2016".to_owned(),
+                        exception: None,
+                    },
+                ]),
+            ])),
+            CrateLicense::parse("(MIT AND Unicode-DFS-2016) OR Apache-2.0 WITH LLVM-exception")
+        );
+    }
+
+    #[test]
+    fn parse_normalizes_expressions() {
+        let test_cases = vec![
+            ("MIT", "MIT"),
+            ("MIT OR Apache-2.0", "Apache-2.0 OR MIT"),
+            ("Apache-2.0 or MIT", "Apache-2.0 OR MIT"),
+            ("MIT/Apache-2.0", "Apache-2.0 OR MIT"),
+            ("MIT / Apache-2.0 / MIT", "Apache-2.0 OR MIT"),
+            ("(MIT)", "MIT"),
+            ("MIT OR (Zlib OR Apache-2.0)", "Apache-2.0 OR MIT OR Zlib"),
+            ("MIT AND Zlib OR Apache-2.0", "Apache-2.0 OR (MIT AND Zlib)"),
+            (
+                "MIT AND (Zlib OR Apache-2.0)",
+                "(Apache-2.0 OR Zlib) AND MIT",
+            ),
+            (
+                "GPL-2.0+ WITH Classpath-exception-2.0",
+                "GPL-2.0+ WITH Classpath-exception-2.0",
+            ),
+            (
+                "bsd-3-clause OR BSD-2-Clause",
+                "BSD-2-Clause OR bsd-3-clause",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(expected, CrateLicense::parse(value).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn parse_compares_expressions_by_meaning() {
+        assert_eq!(
+            CrateLicense::parse("MIT OR Apache-2.0"),
+            CrateLicense::parse("Apache-2.0/MIT")
+        );
+        assert_ne!(
+            CrateLicense::parse("MIT OR Apache-2.0"),
+            CrateLicense::parse("MIT AND Apache-2.0")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        let test_cases = vec![
+            ("", "expected license but found end of input at position 0"),
+            (
+                "MIT OR",
+                "expected license but found end of input at position 6",
+            ),
+            (
+                "MIT AND OR Zlib",
+                "expected license but found `OR` at position 8",
+            ),
+            ("(MIT", "expected `)` but found end of input at position 4"),
+            ("MIT)", "unexpected `)` at position 3"),
+            ("MIT Zlib", "unexpected `Z` at position 4"),
+            (
+                "GPL-2.0 WITH",
+                "expected exception but found end of input at position 12",
+            ),
+        ];
+
+        for (value, reason) in test_cases {
+            assert_eq!(
+                Err(format!(
+                    "{} is not a valid license expression: {}.",
+                    value, reason
+                )),
+                CrateLicense::parse(value)
+            );
+        }
+    }
+
+    #[test]
+    fn licenses() {
+        assert_eq!(
+            vec!["Apache-2.0", "MIT", "Zlib"],
+            CrateLicense::parse("MIT OR (Apache-2.0 AND Zlib) OR MIT")
+                .unwrap()
+                .licenses()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use crate::domain::{
+    CrateDependency, CrateDependencyType, CrateFeatures, CrateMetadata, CrateName,
+    CrateRequirement, CrateTarget, CrateVersion,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateManifest {
+    pub metadata: CrateMetadata,
+    pub features: CrateFeatures,
+    /// SPDX license expression of the package, unknown when it only ships a license file.
+    pub license: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ManifestToml {
+    package: PackageToml,
+    #[serde(flatten)]
+    dependencies: DependenciesToml,
+    #[serde(default)]
+    target: BTreeMap<String, DependenciesToml>,
+    #[serde(default)]
+    features: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct PackageToml {
+    name: String,
+    version: Option<String>,
+    license: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DependenciesToml {
+    #[serde(default)]
+    dependencies: BTreeMap<String, DependencyToml>,
+    #[serde(rename = "build-dependencies", alias = "build_dependencies", default)]
+    build_dependencies: BTreeMap<String, DependencyToml>,
+    #[serde(rename = "dev-dependencies", alias = "dev_dependencies", default)]
+    dev_dependencies: BTreeMap<String, DependencyToml>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DependencyToml {
+    Requirement(String),
+    Detailed(DetailedDependencyToml),
+}
+
+#[derive(Deserialize)]
+struct DetailedDependencyToml {
+    version: Option<String>,
+    package: Option<String>,
+    #[serde(default)]
+    optional: bool,
+    #[serde(rename = "default-features", alias = "default_features")]
+    default_features: Option<bool>,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+impl CrateManifest {
+    /// Parses the dependencies and features of a `Cargo.toml`. Renamed dependencies are recorded
+    /// under the name of the package they refer to, including where the features table refers to
+    /// them, since the registry only knows packages by that name.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let manifest = toml::from_str::<ManifestToml>(value)
+            .map_err(|e| format!("Cargo.toml is not valid: {}.", e))?;
+
+        let name = CrateName::parse(&manifest.package.name)?;
+        let version = CrateVersion::parse(manifest.package.version.as_deref().unwrap_or("0.0.0"))?;
+
+        let mut aliases = BTreeMap::new();
+        let mut dependencies = Vec::new();
+
+        let tables = std::iter::once((None, &manifest.dependencies)).chain(
+            manifest
+                .target
+                .iter()
+                .map(|(target, table)| (Some(target), table)),
+        );
+
+        for (target, table) in tables {
+            let target = target
+                .map(|target| CrateTarget::parse(target))
+                .transpose()?;
+
+            for (type_, dependencies_toml) in &[
+                (CrateDependencyType::Normal, &table.dependencies),
+                (CrateDependencyType::Build, &table.build_dependencies),
+                (CrateDependencyType::Dev, &table.dev_dependencies),
+            ] {
+                for (key, dependency) in dependencies_toml.iter() {
+                    let dependency = parse_dependency(key, dependency, type_, &target)?;
+
+                    if dependency.name.as_str() != key {
+                        aliases.insert(key.clone(), dependency.name.as_str().to_owned());
+                    }
+
+                    dependencies.push(dependency);
+                }
+            }
+        }
+
+        Ok(CrateManifest {
+            metadata: CrateMetadata {
+                name,
+                version,
+                dependencies,
+            },
+            features: CrateFeatures::new(rename_features(manifest.features, &aliases)),
+            license: manifest.package.license,
+        })
+    }
+}
+
+fn parse_dependency(
+    key: &str,
+    dependency: &DependencyToml,
+    type_: &CrateDependencyType,
+    target: &Option<CrateTarget>,
+) -> Result<CrateDependency, String> {
+    let detailed = match dependency {
+        DependencyToml::Requirement(requirement) => {
+            return Ok(CrateDependency {
+                name: CrateName::parse(key)?,
+                requirement: CrateRequirement::parse(requirement)?,
+                type_: type_.clone(),
+                optional: false,
+                default_features: true,
+                features: vec![],
+                target: target.clone(),
+            })
+        }
+        DependencyToml::Detailed(detailed) => detailed,
+    };
+
+    let requirement = detailed.version.as_deref().ok_or_else(|| {
+        format!(
+            "{} has no version requirement. Only dependencies published on crates.io can be resolved.",
+            key
+        )
+    })?;
+
+    Ok(CrateDependency {
+        name: CrateName::parse(detailed.package.as_deref().unwrap_or(key))?,
+        requirement: CrateRequirement::parse(requirement)?,
+        type_: type_.clone(),
+        optional: detailed.optional,
+        default_features: detailed.default_features.unwrap_or(true),
+        features: detailed.features.clone(),
+        target: target.clone(),
+    })
+}
+
+/// Optional renamed dependencies keep the feature Cargo implicitly names after them, so it is
+/// declared explicitly before the renamed dependency is replaced with its package name.
+fn rename_features(
+    mut features: BTreeMap<String, Vec<String>>,
+    aliases: &BTreeMap<String, String>,
+) -> BTreeMap<String, Vec<String>> {
+    for (alias, package) in aliases {
+        let is_explicit = features
+            .values()
+            .flatten()
+            .any(|value| value.strip_prefix("dep:") == Some(alias));
+
+        if !is_explicit && !features.contains_key(alias) {
+            features.insert(alias.clone(), vec![format!("dep:{}", package)]);
+        }
+    }
+
+    for values in features.values_mut() {
+        for value in values.iter_mut() {
+            let (prefix, name, suffix) = match value.find('/') {
+                Some(index) => {
+                    let name = &value[..index];
+                    match name.strip_suffix('?') {
+                        Some(name) => ("", name, &value[name.len()..]),
+                        None => ("", name, &value[index..]),
+                    }
+                }
+                None => match value.strip_prefix("dep:") {
+                    Some(name) => ("dep:", name, ""),
+                    None => continue,
+                },
+            };
+
+            if let Some(package) = aliases.get(name) {
+                *value = format!("{}{}{}", prefix, package, suffix);
+            }
+        }
+    }
+
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let manifest = CrateManifest::parse(
+            r#"
+[package]
+name = "rust-kata-003"
+version = "0.1.0"
+edition = "2018"
+license = "MIT OR Apache-2.0"
+
+[dependencies]
+serde = { version = "1.0", default-features = false, features = ["derive"] }
+rand_core = { version = "0.6", package = "rand_core", optional = true }
+xid = { version = "0.2", package = "unicode-xid", optional = true }
+internal = { path = "../internal", version = "0.3" }
+
+[dev-dependencies]
+wiremock = "0.4.9"
+
+[build-dependencies]
+cc = "1.0"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[features]
+default = ["xid/bench"]
+std = ["rand_core?/std"]
+"#,
+        )
+        .unwrap();
+
+        let dependency =
+            |name: &str, requirement: &str, type_: CrateDependencyType| CrateDependency {
+                name: CrateName::parse(name).unwrap(),
+                requirement: CrateRequirement::parse(requirement).unwrap(),
+                type_,
+                optional: false,
+                default_features: true,
+                features: vec![],
+                target: None,
+            };
+
+        assert_eq!(
+            CrateManifest {
+                metadata: CrateMetadata {
+                    name: CrateName::parse("rust-kata-003").unwrap(),
+                    version: CrateVersion::parse("0.1.0").unwrap(),
+                    dependencies: vec![
+                        dependency("internal", "0.3", CrateDependencyType::Normal),
+                        CrateDependency {
+                            optional: true,
+                            ..dependency("rand_core", "0.6", CrateDependencyType::Normal)
+                        },
+                        CrateDependency {
+                            default_features: false,
+                            features: vec!["derive".to_owned()],
+                            ..dependency("serde", "1.0", CrateDependencyType::Normal)
+                        },
+                        CrateDependency {
+                            optional: true,
+                            ..dependency("unicode-xid", "0.2", CrateDependencyType::Normal)
+                        },
+                        dependency("cc", "1.0", CrateDependencyType::Build),
+                        dependency("wiremock", "0.4.9", CrateDependencyType::Dev),
+                        CrateDependency {
+                            target: Some(CrateTarget::parse("cfg(unix)").unwrap()),
+                            ..dependency("libc", "0.2", CrateDependencyType::Normal)
+                        },
+                    ],
+                },
+                features: CrateFeatures::new(
+                    vec![
+                        ("default".to_owned(), vec!["unicode-xid/bench".to_owned()]),
+                        ("std".to_owned(), vec!["rand_core?/std".to_owned()]),
+                        ("xid".to_owned(), vec!["dep:unicode-xid".to_owned()]),
+                    ]
+                    .into_iter()
+                    .collect()
+                ),
+                license: Some("MIT OR Apache-2.0".to_owned()),
+            },
+            manifest
+        );
+    }
+
+    #[test]
+    fn parse_defaults_version() {
+        let manifest = CrateManifest::parse("[package]\nname = \"internal\"").unwrap();
+
+        assert_eq!("0.0.0", manifest.metadata.version.as_str());
+        assert!(manifest.metadata.dependencies.is_empty());
+        assert_eq!(None, manifest.license);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_manifests() {
+        let test_cases =
+            vec![
+            ("[dependencies]\nserde = \"1.0\"", "Cargo.toml is not valid: "),
+            (
+                "[package]\nname = \"internal\"\n[dependencies]\nother = { path = \"../other\" }",
+                "other has no version requirement. ",
+            ),
+            (
+                "[package]\nname = \"internal\"\n[target.'cfg(unix'.dependencies]\nlibc = \"0.2\"",
+                "cfg(unix is not a valid cfg expression",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            let result = CrateManifest::parse(value).unwrap_err();
+            assert!(result.starts_with(expected), "{}", result);
+        }
+    }
+}
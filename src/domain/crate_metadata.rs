@@ -1,6 +1,6 @@
 use crate::domain::{CrateDependency, CrateName, CrateVersion};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CrateMetadata {
     pub name: CrateName,
     pub version: CrateVersion,
@@ -1,31 +1,145 @@
-#[derive(Clone, Debug, PartialEq)]
-pub struct CrateName(String);
+use std::hash::{Hash, Hasher};
+
+const MAX_LENGTH: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct CrateName {
+    value: String,
+    canonical: String,
+}
 
 impl CrateName {
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// crates.io treats `-` and `_` as well as letter case as equivalent, so `Serde_Json` and
+    /// `serde-json` share the same canonical form.
+    pub fn as_canonical_str(&self) -> &str {
+        &self.canonical
     }
 
     pub fn parse(value: &str) -> Result<Self, String> {
-        Ok(Self(value.to_owned()))
+        let error = |reason: &str| format!("{} is not a valid crate name: {}.", value, reason);
+
+        let first = value
+            .chars()
+            .next()
+            .ok_or_else(|| error("it must not be empty"))?;
+
+        if value.len() > MAX_LENGTH {
+            return Err(error(&format!(
+                "it must not be longer than {} characters",
+                MAX_LENGTH
+            )));
+        }
+
+        if !first.is_ascii_alphabetic() {
+            return Err(error("it must start with an ASCII letter"));
+        }
+
+        if !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(error(
+                "it must only contain ASCII alphanumeric characters, `-` or `_`",
+            ));
+        }
+
+        Ok(Self {
+            value: value.to_owned(),
+            canonical: value.to_ascii_lowercase().replace('_', "-"),
+        })
+    }
+}
+
+impl PartialEq for CrateName {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl Eq for CrateName {}
+
+impl Hash for CrateName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical.hash(state);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fake::{Fake, Faker};
 
     #[test]
     fn as_str() {
-        let value = Faker.fake::<String>();
-        let result = CrateName::parse(&value).unwrap();
+        let value = "Serde_Json";
+        let result = CrateName::parse(value).unwrap();
         assert_eq!(value, result.as_str());
     }
 
+    #[test]
+    fn as_canonical_str() {
+        assert_eq!(
+            "serde-json",
+            CrateName::parse("Serde_Json").unwrap().as_canonical_str()
+        );
+        assert_eq!(
+            "serde-json",
+            CrateName::parse("serde-json").unwrap().as_canonical_str()
+        );
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(
+            CrateName::parse("Serde_Json").unwrap(),
+            CrateName::parse("serde-json").unwrap()
+        );
+        assert_ne!(
+            CrateName::parse("serde").unwrap(),
+            CrateName::parse("serde-json").unwrap()
+        );
+    }
+
     #[test]
     fn parse() {
-        let result = CrateName::parse(&Faker.fake::<String>());
-        assert!(result.is_ok());
+        let longest = "a".repeat(64);
+
+        for value in &["a", "proc-macro2", "unicode_xid", "Inflector", &longest] {
+            assert!(CrateName::parse(value).is_ok(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_names() {
+        let too_long = "a".repeat(65);
+
+        let test_cases = vec![
+            ("", "it must not be empty"),
+            (&too_long[..], "it must not be longer than 64 characters"),
+            ("2d", "it must start with an ASCII letter"),
+            ("-serde", "it must start with an ASCII letter"),
+            (
+                "serde/json",
+                "it must only contain ASCII alphanumeric characters, `-` or `_`",
+            ),
+            (
+                "serde json",
+                "it must only contain ASCII alphanumeric characters, `-` or `_`",
+            ),
+            (
+                "sérde",
+                "it must only contain ASCII alphanumeric characters, `-` or `_`",
+            ),
+        ];
+
+        for (value, reason) in test_cases {
+            assert_eq!(
+                Err(format!("{} is not a valid crate name: {}.", value, reason)),
+                CrateName::parse(value)
+            );
+        }
     }
 }
@@ -0,0 +1,119 @@
+use crate::domain::{CrateRelease, CrateRequirement, CrateVersion, CrateVersionSelector};
+
+/// Versions of a dependency compared when looking for outdated requirements, in the spirit of
+/// `cargo outdated`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateOutdated {
+    /// Version the requirement resolves to, unknown when no published version matches it.
+    pub resolved: Option<CrateVersion>,
+    /// Newest version that is semver compatible with the resolved version, unknown when the
+    /// resolved version is.
+    pub compatible: Option<CrateVersion>,
+    /// Newest version that is neither yanked nor a pre-release.
+    pub latest: Option<CrateVersion>,
+    /// Whether the requirement has to change to accept the latest version, which is only the case
+    /// when the latest version is newer than the resolved one.
+    pub requires_bump: bool,
+}
+
+impl CrateOutdated {
+    /// Compares the requirement against the published `releases`. Yanked versions are skipped,
+    /// and so are pre-releases unless the requirement resolves to one.
+    pub fn new(requirement: &CrateRequirement, releases: &[CrateRelease]) -> Self {
+        let resolved = CrateVersionSelector::Requirement(requirement.clone())
+            .select(releases)
+            .cloned();
+
+        let compatible = resolved.as_ref().and_then(|resolved| {
+            releases
+                .iter()
+                .filter(|release| !release.yanked)
+                .map(|release| &release.version)
+                .filter(|version| resolved.is_prerelease() || !version.is_prerelease())
+                .filter(|version| version.is_compatible_with(resolved) && *version >= resolved)
+                .max()
+                .cloned()
+        });
+
+        let latest = CrateVersionSelector::Latest.select(releases).cloned();
+
+        let requires_bump = match (&resolved, &latest) {
+            (Some(resolved), Some(latest)) => latest > resolved && !requirement.matches(latest),
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+
+        CrateOutdated {
+            resolved,
+            compatible,
+            latest,
+            requires_bump,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::release;
+
+    #[test]
+    fn new() {
+        let releases = [
+            release("0.9.0", false),
+            release("1.0.0", false),
+            release("1.0.5", false),
+            release("1.1.0", false),
+            release("1.2.0", true),
+            release("2.0.0", false),
+            release("3.0.0-alpha.1", false),
+        ];
+
+        let test_cases = vec![
+            ("^1.0", Some("1.1.0"), Some("1.1.0"), Some("2.0.0"), true),
+            ("~1.0", Some("1.0.5"), Some("1.1.0"), Some("2.0.0"), true),
+            ("=1.0.0", Some("1.0.0"), Some("1.1.0"), Some("2.0.0"), true),
+            ("^2", Some("2.0.0"), Some("2.0.0"), Some("2.0.0"), false),
+            (">= 1", Some("2.0.0"), Some("2.0.0"), Some("2.0.0"), false),
+            (
+                "^3.0.0-alpha",
+                Some("3.0.0-alpha.1"),
+                Some("3.0.0-alpha.1"),
+                Some("2.0.0"),
+                false,
+            ),
+            ("^4", None, None, Some("2.0.0"), true),
+        ];
+
+        for (requirement, resolved, compatible, latest, requires_bump) in test_cases {
+            let version = |version: Option<&str>| version.map(|v| CrateVersion::parse(v).unwrap());
+
+            assert_eq!(
+                CrateOutdated {
+                    resolved: version(resolved),
+                    compatible: version(compatible),
+                    latest: version(latest),
+                    requires_bump,
+                },
+                CrateOutdated::new(&CrateRequirement::parse(requirement).unwrap(), &releases),
+                "{}",
+                requirement
+            );
+        }
+    }
+
+    #[test]
+    fn new_without_releases() {
+        let outdated = CrateOutdated::new(&CrateRequirement::parse("^1").unwrap(), &[]);
+
+        assert_eq!(
+            CrateOutdated {
+                resolved: None,
+                compatible: None,
+                latest: None,
+                requires_bump: false,
+            },
+            outdated
+        );
+    }
+}
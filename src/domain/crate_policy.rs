@@ -0,0 +1,272 @@
+use crate::domain::{CrateLicense, CrateName, CrateRequirement, CrateVersion};
+use serde::Deserialize;
+
+/// Rules a dependency graph is checked against, in the spirit of cargo-deny.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CratePolicy {
+    /// Licenses crates may be used under, any license that is not denied when empty.
+    pub allowed_licenses: Vec<String>,
+    pub denied_licenses: Vec<String>,
+    pub banned_crates: Vec<CrateBan>,
+    /// Whether crates may not appear at semver incompatible versions.
+    pub deny_duplicates: bool,
+    /// Whether dependencies may not accept any version of a crate.
+    pub deny_wildcards: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateBan {
+    pub name: CrateName,
+    /// Versions of the crate that are banned, every version when unknown.
+    pub requirement: Option<CrateRequirement>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyToml {
+    #[serde(default)]
+    licenses: LicensesToml,
+    #[serde(default)]
+    bans: BansToml,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LicensesToml {
+    #[serde(default)]
+    allow: Vec<String>,
+    #[serde(default)]
+    deny: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BansToml {
+    #[serde(default)]
+    deny: Vec<BanToml>,
+    #[serde(rename = "multiple-versions")]
+    multiple_versions: Option<LintToml>,
+    wildcards: Option<LintToml>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BanToml {
+    name: String,
+    version: Option<String>,
+}
+
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum LintToml {
+    Allow,
+    Deny,
+}
+
+impl CratePolicy {
+    /// Whether the policy has license rules, in which case every crate needs a valid license
+    /// expression.
+    pub fn checks_licenses(&self) -> bool {
+        !self.allowed_licenses.is_empty() || !self.denied_licenses.is_empty()
+    }
+
+    /// Whether the crate may be used under the license, which takes a single allowed license
+    /// for each choice the expression offers. License identifiers are compared ignoring case.
+    pub fn allows_license(&self, license: &CrateLicense) -> bool {
+        let contains = |licenses: &[String], id: &str| {
+            licenses
+                .iter()
+                .any(|license| license.eq_ignore_ascii_case(id))
+        };
+
+        license.evaluate(&|id| {
+            !contains(&self.denied_licenses, id)
+                && (self.allowed_licenses.is_empty() || contains(&self.allowed_licenses, id))
+        })
+    }
+
+    /// Returns the first ban matching the crate version.
+    pub fn ban(&self, name: &CrateName, version: &CrateVersion) -> Option<&CrateBan> {
+        self.banned_crates.iter().find(|ban| {
+            &ban.name == name
+                && ban
+                    .requirement
+                    .as_ref()
+                    .map(|requirement| requirement.matches(version))
+                    .unwrap_or(true)
+        })
+    }
+
+    /// Parses a policy file in TOML, laid out like the `licenses` and `bans` sections of
+    /// cargo-deny.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let policy = toml::from_str::<PolicyToml>(value)
+            .map_err(|e| format!("Policy is not valid: {}.", e))?;
+
+        for license in policy.licenses.allow.iter().chain(&policy.licenses.deny) {
+            match CrateLicense::parse(license)? {
+                CrateLicense::License {
+                    exception: None, ..
+                } => {}
+                _ => {
+                    return Err(format!(
+                        "Policy is not valid: {} is not a license identifier.",
+                        license
+                    ))
+                }
+            }
+        }
+
+        let banned_crates = policy
+            .bans
+            .deny
+            .iter()
+            .map(|ban| {
+                Ok(CrateBan {
+                    name: CrateName::parse(&ban.name)?,
+                    requirement: ban
+                        .version
+                        .as_deref()
+                        .map(CrateRequirement::parse)
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(CratePolicy {
+            allowed_licenses: policy.licenses.allow,
+            denied_licenses: policy.licenses.deny,
+            banned_crates,
+            deny_duplicates: policy.bans.multiple_versions == Some(LintToml::Deny),
+            deny_wildcards: policy.bans.wildcards == Some(LintToml::Deny),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let policy = CratePolicy::parse(
+            r#"
+[licenses]
+allow = ["MIT", "Apache-2.0"]
+deny = ["GPL-3.0"]
+
+[bans]
+deny = [{ name = "openssl" }, { name = "time", version = "< 0.2" }]
+multiple-versions = "deny"
+wildcards = "deny"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CratePolicy {
+                allowed_licenses: vec!["MIT".to_owned(), "Apache-2.0".to_owned()],
+                denied_licenses: vec!["GPL-3.0".to_owned()],
+                banned_crates: vec![
+                    CrateBan {
+                        name: CrateName::parse("openssl").unwrap(),
+                        requirement: None,
+                    },
+                    CrateBan {
+                        name: CrateName::parse("time").unwrap(),
+                        requirement: Some(CrateRequirement::parse("< 0.2").unwrap()),
+                    },
+                ],
+                deny_duplicates: true,
+                deny_wildcards: true,
+            },
+            policy
+        );
+    }
+
+    #[test]
+    fn parse_defaults_to_allowing_everything() {
+        assert_eq!(CratePolicy::default(), CratePolicy::parse("").unwrap());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_policies() {
+        let test_cases = vec![
+            ("[bans]\nwildcards = \"warn\"", "Policy is not valid: "),
+            ("[advisories]\nignore = []", "Policy is not valid: "),
+            (
+                "[licenses]\nallow = [\"MIT OR Apache-2.0\"]",
+                "Policy is not valid: MIT OR Apache-2.0 is not a license identifier.",
+            ),
+            (
+                "[licenses]\ndeny = [\"GPL-3.0 AND\"]",
+                "GPL-3.0 AND is not a valid license expression: ",
+            ),
+            (
+                "[bans]\ndeny = [{ name = \"time\", version = \"0.1.2.3\" }]",
+                "0.1.2.3 is not a valid version requirement: ",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            let result = CratePolicy::parse(value).unwrap_err();
+            assert!(result.starts_with(expected), "{}", result);
+        }
+    }
+
+    #[test]
+    fn allows_license() {
+        let policy = CratePolicy {
+            allowed_licenses: vec!["MIT".to_owned(), "Apache-2.0".to_owned()],
+            denied_licenses: vec!["Apache-2.0".to_owned()],
+            ..CratePolicy::default()
+        };
+
+        let test_cases = vec![
+            ("MIT", true),
+            ("mit", true),
+            ("Apache-2.0", false),
+            ("Apache-2.0 OR MIT", true),
+            ("Apache-2.0 AND MIT", false),
+            ("Zlib", false),
+        ];
+
+        for (value, expected) in test_cases {
+            let license = CrateLicense::parse(value).unwrap();
+            assert_eq!(expected, policy.allows_license(&license), "{}", value);
+        }
+
+        let denying = CratePolicy {
+            denied_licenses: vec!["GPL-3.0".to_owned()],
+            ..CratePolicy::default()
+        };
+        assert!(denying.allows_license(&CrateLicense::parse("Zlib").unwrap()));
+        assert!(!denying.allows_license(&CrateLicense::parse("GPL-3.0").unwrap()));
+    }
+
+    #[test]
+    fn ban() {
+        let policy = CratePolicy::parse(
+            r#"
+[bans]
+deny = [{ name = "openssl" }, { name = "time", version = "< 0.2" }]
+"#,
+        )
+        .unwrap();
+
+        let test_cases = vec![
+            ("openssl", "0.10.0", true),
+            ("time", "0.1.44", true),
+            ("time", "0.2.0", false),
+            ("chrono", "0.4.0", false),
+        ];
+
+        for (name, version, expected) in test_cases {
+            let result = policy.ban(
+                &CrateName::parse(name).unwrap(),
+                &CrateVersion::parse(version).unwrap(),
+            );
+            assert_eq!(expected, result.is_some(), "{} {}", name, version);
+        }
+    }
+}
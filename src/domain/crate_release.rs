@@ -0,0 +1,15 @@
+use crate::domain::{CrateFeatures, CrateVersion};
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateRelease {
+    pub version: CrateVersion,
+    pub created_at: DateTime<Utc>,
+    pub yanked: bool,
+    pub checksum: String,
+    pub rust_version: Option<String>,
+    /// SPDX license expression as crates.io lists it, unknown when the crate only ships a
+    /// license file.
+    pub license: Option<String>,
+    pub features: CrateFeatures,
+}
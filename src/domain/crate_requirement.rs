@@ -1,13 +1,43 @@
-#[derive(Debug, PartialEq)]
-pub struct CrateRequirement(String);
+use crate::domain::CrateVersion;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrateRequirement {
+    value: String,
+    semver: semver::VersionReq,
+}
 
 impl CrateRequirement {
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    pub fn best_match<'a, I>(&self, versions: I) -> Option<&'a CrateVersion>
+    where
+        I: IntoIterator<Item = &'a CrateVersion>,
+    {
+        versions
+            .into_iter()
+            .filter(|version| self.matches(version))
+            .max()
+    }
+
+    /// Whether the requirement accepts any version, as `*` does.
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self.value.trim(), "*" | "x" | "X")
+    }
+
+    pub fn matches(&self, version: &CrateVersion) -> bool {
+        self.semver.matches(version.as_semver())
     }
 
     pub fn parse(value: &str) -> Result<Self, String> {
-        Ok(Self(value.to_owned()))
+        let semver = semver::VersionReq::parse(value)
+            .map_err(|e| format!("{} is not a valid version requirement: {}.", value, e))?;
+
+        Ok(Self {
+            value: value.to_owned(),
+            semver,
+        })
     }
 }
 
@@ -18,14 +48,111 @@ mod tests {
 
     #[test]
     fn as_str() {
-        let value = Faker.fake::<String>();
-        let result = CrateRequirement::parse(&value).unwrap();
+        let value = ">=1.2.3, <2.0.0";
+        let result = CrateRequirement::parse(value).unwrap();
         assert_eq!(value, result.as_str());
     }
 
+    #[test]
+    fn best_match() {
+        let versions = versions(&["0.2.0", "0.2.21", "0.2.3", "0.3.0", "0.2.22-alpha.1"]);
+
+        assert_eq!(
+            Some(&version("0.2.21")),
+            requirement("^0.2.1").best_match(&versions)
+        );
+        assert_eq!(
+            Some(&version("0.3.0")),
+            requirement("*").best_match(&versions)
+        );
+        assert_eq!(None, requirement("^0.4").best_match(&versions));
+        assert_eq!(None, requirement("^0.2").best_match(&[]));
+    }
+
+    #[test]
+    fn is_wildcard() {
+        let test_cases = vec![("*", true), ("x", true), ("1.*", false), ("^1.2.3", false)];
+
+        for (value, expected) in test_cases {
+            assert_eq!(expected, requirement(value).is_wildcard(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn matches() {
+        let test_cases = vec![
+            // caret
+            ("^1.2.3", "1.2.3", true),
+            ("^1.2.3", "1.9.0", true),
+            ("^1.2.3", "2.0.0", false),
+            ("^0.2.3", "0.2.9", true),
+            ("^0.2.3", "0.3.0", false),
+            ("^0.0.3", "0.0.4", false),
+            ("1.2.3", "1.9.0", true),
+            // tilde
+            ("~1.2.3", "1.2.9", true),
+            ("~1.2.3", "1.3.0", false),
+            ("~1", "1.9.0", true),
+            // wildcard
+            ("*", "3.4.5", true),
+            ("1.*", "1.9.0", true),
+            ("1.2.*", "1.3.0", false),
+            // comparison
+            (">=1.2.0", "1.2.0", true),
+            (">1.2.0", "1.2.0", false),
+            ("<1.2.0", "1.1.9", true),
+            ("=1.2.0", "1.2.1", false),
+            // comma separated sets
+            (">=1.2, <1.5", "1.4.9", true),
+            (">=1.2, <1.5", "1.5.0", false),
+            // pre-release
+            ("^1.2.3", "1.3.0-alpha.1", false),
+            ("^1.2.3-alpha.1", "1.2.3-alpha.2", true),
+            ("^1.2.3-alpha.1", "1.2.4-alpha.1", false),
+            ("^1.2.3-alpha.1", "1.2.4", true),
+        ];
+
+        for (requirement_value, version_value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                requirement(requirement_value).matches(&version(version_value)),
+                "{} matches {}",
+                requirement_value,
+                version_value
+            );
+        }
+    }
+
     #[test]
     fn parse() {
-        let result = CrateRequirement::parse(&Faker.fake::<String>());
-        assert!(result.is_ok());
+        for value in &["^0.2.22", "~1.2", "1.*", "*", ">= 1.2, < 2", "=1.0.0-rc.1"] {
+            assert!(CrateRequirement::parse(value).is_ok(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_requirements() {
+        for value in &["", "^", "1.2.3.4", ">=1.2 <2", "latest"] {
+            let result = CrateRequirement::parse(value);
+            assert!(result.is_err(), "{} should not parse", value);
+            assert!(result
+                .unwrap_err()
+                .starts_with(&format!("{} is not a valid version requirement: ", value)));
+        }
+
+        let value = format!("{}^", Faker.fake::<String>());
+        assert!(CrateRequirement::parse(&value).is_err());
+    }
+
+    fn requirement(value: &str) -> CrateRequirement {
+        CrateRequirement::parse(value).unwrap()
+    }
+
+    fn version(value: &str) -> CrateVersion {
+        CrateVersion::parse(value).unwrap()
+    }
+
+    fn versions(values: &[&str]) -> Vec<CrateVersion> {
+        values.iter().map(|value| version(value)).collect()
     }
 }
@@ -0,0 +1,183 @@
+use crate::domain::{CfgExpression, TargetPlatform};
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrateTarget {
+    Cfg(CfgExpression),
+    Triple(String),
+}
+
+impl CrateTarget {
+    pub fn applies_to(&self, platform: &TargetPlatform) -> bool {
+        match self {
+            CrateTarget::Cfg(expression) => {
+                expression.evaluate(&|key, value| platform.is_set(key, value))
+            }
+            CrateTarget::Triple(triple) => triple == platform.as_str(),
+        }
+    }
+
+    /// Returns the built-in platforms the target applies to.
+    pub fn platforms(&self) -> Vec<&'static TargetPlatform> {
+        TargetPlatform::all()
+            .iter()
+            .filter(|platform| self.applies_to(platform))
+            .collect()
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value.starts_with("cfg(") {
+            if !value.ends_with(')') {
+                return Err(format!(
+                    "{} is not a valid cfg expression: expected `)` but found end of input at position {}.",
+                    value,
+                    value.len()
+                ));
+            }
+
+            return CfgExpression::parse_within(value, 4, value.len() - 1).map(CrateTarget::Cfg);
+        }
+
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        {
+            return Err(format!(
+                "{} is not a valid target: use either a target triple or a `cfg(...)` expression.",
+                value
+            ));
+        }
+
+        Ok(CrateTarget::Triple(value.to_owned()))
+    }
+}
+
+impl fmt::Display for CrateTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrateTarget::Cfg(expression) => write!(f, "cfg({})", expression),
+            CrateTarget::Triple(triple) => write!(f, "{}", triple),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_to() {
+        let platforms = [
+            TargetPlatform::parse("x86_64-unknown-linux-gnu").unwrap(),
+            TargetPlatform::parse("x86_64-apple-darwin").unwrap(),
+            TargetPlatform::parse("x86_64-pc-windows-msvc").unwrap(),
+            TargetPlatform::parse("wasm32-unknown-unknown").unwrap(),
+        ];
+
+        let test_cases = vec![
+            ("cfg(unix)", vec![true, true, false, false]),
+            ("cfg(windows)", vec![false, false, true, false]),
+            (
+                r#"cfg(all(unix, not(target_os = "macos")))"#,
+                vec![true, false, false, false],
+            ),
+            (
+                r#"cfg(any(target_arch = "wasm32", target_os = "macos"))"#,
+                vec![false, true, false, true],
+            ),
+            (
+                r#"cfg(target_env = "msvc")"#,
+                vec![false, false, true, false],
+            ),
+            (
+                r#"cfg(target_pointer_width = "64")"#,
+                vec![true, true, true, false],
+            ),
+            ("cfg(all())", vec![true, true, true, true]),
+            ("cfg(any())", vec![false, false, false, false]),
+            ("x86_64-pc-windows-msvc", vec![false, false, true, false]),
+        ];
+
+        for (value, expected) in test_cases {
+            let target = CrateTarget::parse(value).unwrap();
+            let actual = platforms
+                .iter()
+                .map(|platform| target.applies_to(platform))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, actual, "{}", value);
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(CrateTarget::Cfg(CfgExpression::Name("unix".to_owned()))),
+            CrateTarget::parse("cfg(unix)")
+        );
+        assert_eq!(
+            Ok(CrateTarget::Triple("x86_64-pc-windows-gnu".to_owned())),
+            CrateTarget::parse("x86_64-pc-windows-gnu")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_targets() {
+        let test_cases = vec![
+            (
+                "cfg(unix",
+                "cfg(unix is not a valid cfg expression: expected `)` but found end of input at position 8.",
+            ),
+            (
+                "cfg(all(unix)",
+                "cfg(all(unix) is not a valid cfg expression: expected `)` but found end of input at position 12.",
+            ),
+            (
+                "cfg(unix windows)",
+                "cfg(unix windows) is not a valid cfg expression: unexpected `w` at position 9.",
+            ),
+            (
+                "",
+                " is not a valid target: use either a target triple or a `cfg(...)` expression.",
+            ),
+            (
+                "x86_64 linux",
+                "x86_64 linux is not a valid target: use either a target triple or a `cfg(...)` expression.",
+            ),
+        ];
+
+        for (value, error) in test_cases {
+            assert_eq!(Err(error.to_owned()), CrateTarget::parse(value));
+        }
+    }
+
+    #[test]
+    fn platforms() {
+        let target = CrateTarget::parse(r#"cfg(all(windows, target_arch = "x86"))"#).unwrap();
+
+        assert_eq!(
+            vec!["i686-pc-windows-gnu", "i686-pc-windows-msvc"],
+            target
+                .platforms()
+                .iter()
+                .map(|platform| platform.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            r#"cfg(all(unix, not(target_os = "macos")))"#,
+            CrateTarget::parse(r#"cfg(all(unix,not(target_os="macos")))"#)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "x86_64-pc-windows-gnu",
+            CrateTarget::parse("x86_64-pc-windows-gnu")
+                .unwrap()
+                .to_string()
+        );
+    }
+}
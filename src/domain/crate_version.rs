@@ -1,13 +1,54 @@
-#[derive(Clone, Debug, PartialEq)]
-pub struct CrateVersion(String);
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CrateVersion {
+    value: String,
+    semver: semver::Version,
+}
 
 impl CrateVersion {
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    pub fn as_semver(&self) -> &semver::Version {
+        &self.semver
+    }
+
+    /// Versions are compatible when they share the leftmost non-zero component, which is when
+    /// Cargo can unify them into a single version of the crate.
+    pub fn is_compatible_with(&self, other: &CrateVersion) -> bool {
+        match (&self.semver, &other.semver) {
+            (a, b) if a.major != 0 || b.major != 0 => a.major == b.major,
+            (a, b) if a.minor != 0 || b.minor != 0 => a.minor == b.minor,
+            (a, b) => a.patch == b.patch,
+        }
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.semver.pre.is_empty()
     }
 
     pub fn parse(value: &str) -> Result<Self, String> {
-        Ok(Self(value.to_owned()))
+        let semver = semver::Version::parse(value)
+            .map_err(|e| format!("{} is not a valid semantic version: {}.", value, e))?;
+
+        Ok(Self {
+            value: semver.to_string(),
+            semver,
+        })
+    }
+}
+
+impl Ord for CrateVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.semver.cmp(&other.semver)
+    }
+}
+
+impl PartialOrd for CrateVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -18,14 +59,94 @@ mod tests {
 
     #[test]
     fn as_str() {
-        let value = Faker.fake::<String>();
-        let result = CrateVersion::parse(&value).unwrap();
+        let value = "1.2.3-alpha.1+build.5";
+        let result = CrateVersion::parse(value).unwrap();
         assert_eq!(value, result.as_str());
     }
 
+    #[test]
+    fn is_compatible_with() {
+        let test_cases = vec![
+            ("1.0.0", "1.9.3", true),
+            ("1.0.0", "2.0.0", false),
+            ("0.2.0", "0.2.1", true),
+            ("0.1.0", "0.2.0", false),
+            ("0.0.3", "0.0.3", true),
+            ("0.0.3", "0.0.4", false),
+            ("0.1.0", "1.1.0", false),
+            ("1.0.0-alpha", "1.0.0", true),
+        ];
+
+        for (a, b, expected) in test_cases {
+            let a = CrateVersion::parse(a).unwrap();
+            let b = CrateVersion::parse(b).unwrap();
+            assert_eq!(
+                expected,
+                a.is_compatible_with(&b),
+                "{} {}",
+                a.as_str(),
+                b.as_str()
+            );
+            assert_eq!(
+                expected,
+                b.is_compatible_with(&a),
+                "{} {}",
+                b.as_str(),
+                a.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn is_prerelease() {
+        assert!(!CrateVersion::parse("1.2.3").unwrap().is_prerelease());
+        assert!(!CrateVersion::parse("1.2.3+build.5")
+            .unwrap()
+            .is_prerelease());
+        assert!(CrateVersion::parse("1.2.3-alpha.1")
+            .unwrap()
+            .is_prerelease());
+    }
+
     #[test]
     fn parse() {
-        let result = CrateVersion::parse(&Faker.fake::<String>());
-        assert!(result.is_ok());
+        let result = CrateVersion::parse("1.2.3-alpha.1+build.5").unwrap();
+        assert_eq!(1, result.as_semver().major);
+        assert_eq!(2, result.as_semver().minor);
+        assert_eq!(3, result.as_semver().patch);
+        assert_eq!("alpha.1", result.as_semver().pre.as_str());
+        assert_eq!("build.5", result.as_semver().build.as_str());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_versions() {
+        for value in &["", "1", "1.2", "01.2.3", "1.2.3-", "v1.2.3", " 1.2.3"] {
+            let result = CrateVersion::parse(value);
+            assert!(result.is_err(), "{} should not parse", value);
+            assert!(result
+                .unwrap_err()
+                .starts_with(&format!("{} is not a valid semantic version: ", value)));
+        }
+
+        let value = format!("{}.", Faker.fake::<String>());
+        assert!(CrateVersion::parse(&value).is_err());
+    }
+
+    #[test]
+    fn ord() {
+        let mut versions = [
+            CrateVersion::parse("1.0.0").unwrap(),
+            CrateVersion::parse("0.10.0").unwrap(),
+            CrateVersion::parse("1.0.0-rc.1").unwrap(),
+            CrateVersion::parse("0.9.1").unwrap(),
+            CrateVersion::parse("1.0.0-alpha").unwrap(),
+        ];
+
+        versions.sort();
+
+        assert_eq!(
+            vec!["0.9.1", "0.10.0", "1.0.0-alpha", "1.0.0-rc.1", "1.0.0"],
+            versions.iter().map(|v| v.as_str()).collect::<Vec<_>>()
+        );
     }
 }
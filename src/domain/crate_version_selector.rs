@@ -0,0 +1,165 @@
+use crate::domain::{CrateRelease, CrateRequirement, CrateVersion};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrateVersionSelector {
+    Exact(CrateVersion),
+    Latest,
+    Requirement(CrateRequirement),
+}
+
+impl CrateVersionSelector {
+    /// Selects a version from the published `releases`. Yanked versions are only selected when
+    /// asked for exactly, and `latest` is the newest version that is not a pre-release either.
+    pub fn select<'a, I>(&self, releases: I) -> Option<&'a CrateVersion>
+    where
+        I: IntoIterator<Item = &'a CrateRelease>,
+    {
+        let releases = releases.into_iter();
+
+        match self {
+            CrateVersionSelector::Exact(version) => releases
+                .map(|release| &release.version)
+                .find(|candidate| *candidate == version),
+            CrateVersionSelector::Latest => releases
+                .filter(|release| !release.yanked && !release.version.is_prerelease())
+                .map(|release| &release.version)
+                .max(),
+            CrateVersionSelector::Requirement(requirement) => requirement.best_match(
+                releases
+                    .filter(|release| !release.yanked)
+                    .map(|release| &release.version),
+            ),
+        }
+    }
+
+    /// Returns every version the selector refers to. Requirements refer to each published version
+    /// they match, yanked or not, while exact versions and `latest` refer to a single version.
+    pub fn candidates(&self, releases: &[CrateRelease]) -> Vec<CrateVersion> {
+        match self {
+            CrateVersionSelector::Exact(version) => vec![version.clone()],
+            CrateVersionSelector::Latest => self.select(releases).cloned().into_iter().collect(),
+            CrateVersionSelector::Requirement(requirement) => releases
+                .iter()
+                .map(|release| &release.version)
+                .filter(|version| requirement.matches(version))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value == "latest" {
+            return Ok(CrateVersionSelector::Latest);
+        }
+
+        if let Ok(version) = CrateVersion::parse(value) {
+            return Ok(CrateVersionSelector::Exact(version));
+        }
+
+        CrateRequirement::parse(value)
+            .map(CrateVersionSelector::Requirement)
+            .map_err(|_| {
+                format!(
+                    "{} is not a valid version. Use either a version, a version requirement or `latest`.",
+                    value
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::release;
+
+    #[test]
+    fn candidates() {
+        let releases = [
+            release("1.1.0", false),
+            release("1.2.0", false),
+            release("1.3.0", true),
+            release("2.0.0", false),
+        ];
+
+        let test_cases = vec![
+            ("latest", vec!["2.0.0"]),
+            ("1.4.0", vec!["1.4.0"]),
+            ("^1.2", vec!["1.2.0", "1.3.0"]),
+            ("^3", vec![]),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                CrateVersionSelector::parse(value)
+                    .unwrap()
+                    .candidates(&releases)
+                    .iter()
+                    .map(|version| version.as_str())
+                    .collect::<Vec<_>>(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(CrateVersionSelector::Latest),
+            CrateVersionSelector::parse("latest")
+        );
+        assert_eq!(
+            Ok(CrateVersionSelector::Exact(
+                CrateVersion::parse("1.2.3").unwrap()
+            )),
+            CrateVersionSelector::parse("1.2.3")
+        );
+        assert_eq!(
+            Ok(CrateVersionSelector::Requirement(
+                CrateRequirement::parse("^1.2").unwrap()
+            )),
+            CrateVersionSelector::parse("^1.2")
+        );
+        assert_eq!(
+            Err(
+                "newest is not a valid version. Use either a version, a version requirement or `latest`."
+                    .to_owned()
+            ),
+            CrateVersionSelector::parse("newest")
+        );
+    }
+
+    #[test]
+    fn select() {
+        let releases = [
+            release("1.1.0", false),
+            release("1.2.0", false),
+            release("1.3.0", true),
+            release("2.0.0-alpha.1", false),
+            release("0.9.0", false),
+        ];
+
+        let test_cases = vec![
+            ("latest", Some("1.2.0")),
+            ("1.3.0", Some("1.3.0")),
+            ("1.4.0", None),
+            ("^1.1", Some("1.2.0")),
+            ("=1.3.0", None),
+            ("~1.1", Some("1.1.0")),
+            ("^3", None),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                CrateVersionSelector::parse(value)
+                    .unwrap()
+                    .select(&releases)
+                    .map(|version| version.as_str()),
+                "{}",
+                value
+            );
+        }
+    }
+}
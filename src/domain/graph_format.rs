@@ -0,0 +1,44 @@
+use std::convert::TryFrom;
+
+/// How a resolved dependency graph is returned, either as the JSON graph or as a `Cargo.lock`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphFormat {
+    Json,
+    Lockfile,
+}
+
+impl TryFrom<&str> for GraphFormat {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "json" => Ok(Self::Json),
+            "lockfile" => Ok(Self::Lockfile),
+            other => Err(format!(
+                "{} is not a supported format. Use either `json` or `lockfile`.",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::{Fake, Faker};
+
+    #[test]
+    fn try_from() {
+        assert_eq!(Ok(GraphFormat::Json), GraphFormat::try_from("json"));
+        assert_eq!(Ok(GraphFormat::Lockfile), GraphFormat::try_from("lockfile"));
+
+        let other = Faker.fake::<String>();
+        assert_eq!(
+            Err(format!(
+                "{} is not a supported format. Use either `json` or `lockfile`.",
+                other
+            )),
+            GraphFormat::try_from(other.as_str())
+        );
+    }
+}
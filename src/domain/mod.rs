@@ -1,13 +1,100 @@
+mod cargo_lock;
+mod cfg_expression;
+mod crate_advisory;
+mod crate_advisory_severity;
 mod crate_dependency;
+mod crate_dependency_diff;
+mod crate_dependent;
+mod crate_dependent_source;
+mod crate_features;
+mod crate_license;
+mod crate_manifest;
 mod crate_metadata;
 mod crate_name;
+mod crate_outdated;
+mod crate_policy;
+mod crate_release;
 mod crate_requirement;
+mod crate_target;
 mod crate_version;
+mod crate_version_selector;
 mod create_dependency_type;
+mod graph_format;
+mod target_platform;
 
+pub use cargo_lock::*;
+pub use cfg_expression::*;
+pub use crate_advisory::*;
+pub use crate_advisory_severity::*;
 pub use crate_dependency::*;
+pub use crate_dependency_diff::*;
+pub use crate_dependent::*;
+pub use crate_dependent_source::*;
+pub use crate_features::*;
+pub use crate_license::*;
+pub use crate_manifest::*;
 pub use crate_metadata::*;
 pub use crate_name::*;
+pub use crate_outdated::*;
+pub use crate_policy::*;
+pub use crate_release::*;
 pub use crate_requirement::*;
+pub use crate_target::*;
 pub use crate_version::*;
+pub use crate_version_selector::*;
 pub use create_dependency_type::*;
+pub use graph_format::*;
+pub use target_platform::*;
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::domain::{
+        CrateDependency, CrateDependencyType, CrateFeatures, CrateName, CrateRelease,
+        CrateRequirement, CrateTarget, CrateVersion,
+    };
+    use chrono::{TimeZone, Utc};
+
+    pub fn dependency(
+        name_value: &str,
+        requirement_value: &str,
+        type_: CrateDependencyType,
+    ) -> CrateDependency {
+        CrateDependency {
+            name: name(name_value),
+            requirement: requirement(requirement_value),
+            type_,
+            optional: false,
+            default_features: true,
+            features: vec![],
+            target: None,
+        }
+    }
+
+    pub fn release(version_value: &str, yanked: bool) -> CrateRelease {
+        CrateRelease {
+            version: version(version_value),
+            created_at: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+            yanked,
+            checksum: format!("checksum-{}", version_value),
+            rust_version: None,
+            license: None,
+            features: CrateFeatures::default(),
+        }
+    }
+
+    pub fn name(value: &str) -> CrateName {
+        CrateName::parse(value).unwrap()
+    }
+
+    pub fn requirement(value: &str) -> CrateRequirement {
+        CrateRequirement::parse(value).unwrap()
+    }
+
+    pub fn target(value: &str) -> CrateTarget {
+        CrateTarget::parse(value).unwrap()
+    }
+
+    pub fn version(value: &str) -> CrateVersion {
+        CrateVersion::parse(value).unwrap()
+    }
+}
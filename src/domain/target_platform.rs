@@ -0,0 +1,144 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct TargetPlatform {
+    triple: &'static str,
+    arch: &'static str,
+    os: &'static str,
+    family: &'static str,
+    env: &'static str,
+    vendor: &'static str,
+    endian: &'static str,
+    pointer_width: &'static str,
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn platform(
+    triple: &'static str,
+    arch: &'static str,
+    os: &'static str,
+    family: &'static str,
+    env: &'static str,
+    vendor: &'static str,
+    endian: &'static str,
+    pointer_width: &'static str,
+) -> TargetPlatform {
+    TargetPlatform {
+        triple,
+        arch,
+        os,
+        family,
+        env,
+        vendor,
+        endian,
+        pointer_width,
+    }
+}
+
+#[rustfmt::skip]
+const PLATFORMS: &[TargetPlatform] = &[
+    platform("aarch64-apple-darwin", "aarch64", "macos", "unix", "", "apple", "little", "64"),
+    platform("aarch64-apple-ios", "aarch64", "ios", "unix", "", "apple", "little", "64"),
+    platform("aarch64-linux-android", "aarch64", "android", "unix", "", "unknown", "little", "64"),
+    platform("aarch64-pc-windows-msvc", "aarch64", "windows", "windows", "msvc", "pc", "little", "64"),
+    platform("aarch64-unknown-linux-gnu", "aarch64", "linux", "unix", "gnu", "unknown", "little", "64"),
+    platform("aarch64-unknown-linux-musl", "aarch64", "linux", "unix", "musl", "unknown", "little", "64"),
+    platform("arm-unknown-linux-gnueabihf", "arm", "linux", "unix", "gnu", "unknown", "little", "32"),
+    platform("armv7-unknown-linux-gnueabihf", "arm", "linux", "unix", "gnu", "unknown", "little", "32"),
+    platform("i686-pc-windows-gnu", "x86", "windows", "windows", "gnu", "pc", "little", "32"),
+    platform("i686-pc-windows-msvc", "x86", "windows", "windows", "msvc", "pc", "little", "32"),
+    platform("i686-unknown-linux-gnu", "x86", "linux", "unix", "gnu", "unknown", "little", "32"),
+    platform("wasm32-unknown-emscripten", "wasm32", "emscripten", "unix", "", "unknown", "little", "32"),
+    platform("wasm32-unknown-unknown", "wasm32", "unknown", "", "", "unknown", "little", "32"),
+    platform("wasm32-wasi", "wasm32", "wasi", "", "", "unknown", "little", "32"),
+    platform("x86_64-apple-darwin", "x86_64", "macos", "unix", "", "apple", "little", "64"),
+    platform("x86_64-pc-windows-gnu", "x86_64", "windows", "windows", "gnu", "pc", "little", "64"),
+    platform("x86_64-pc-windows-msvc", "x86_64", "windows", "windows", "msvc", "pc", "little", "64"),
+    platform("x86_64-unknown-freebsd", "x86_64", "freebsd", "unix", "", "unknown", "little", "64"),
+    platform("x86_64-unknown-linux-gnu", "x86_64", "linux", "unix", "gnu", "unknown", "little", "64"),
+    platform("x86_64-unknown-linux-musl", "x86_64", "linux", "unix", "musl", "unknown", "little", "64"),
+    platform("x86_64-unknown-netbsd", "x86_64", "netbsd", "unix", "", "unknown", "little", "64"),
+];
+
+impl TargetPlatform {
+    pub fn all() -> &'static [TargetPlatform] {
+        PLATFORMS
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.triple
+    }
+
+    /// Returns whether the `name` cfg option, such as `unix`, or the `key = "value"` cfg option,
+    /// such as `target_os = "linux"`, is set.
+    pub fn is_set(&self, key: &str, value: Option<&str>) -> bool {
+        match value {
+            Some(value) => self.cfg_value(key) == Some(value),
+            None => matches!(key, "unix" | "windows") && self.family == key,
+        }
+    }
+
+    fn cfg_value(&self, key: &str) -> Option<&str> {
+        let value = match key {
+            "target_arch" => self.arch,
+            "target_endian" => self.endian,
+            "target_env" => self.env,
+            "target_family" if self.family.is_empty() => return None,
+            "target_family" => self.family,
+            "target_os" => self.os,
+            "target_pointer_width" => self.pointer_width,
+            "target_vendor" => self.vendor,
+            _ => return None,
+        };
+
+        Some(value)
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        PLATFORMS
+            .iter()
+            .find(|platform| platform.triple == value)
+            .cloned()
+            .ok_or_else(|| format!("{} is not a supported target.", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_set() {
+        let linux = TargetPlatform::parse("x86_64-unknown-linux-gnu").unwrap();
+        let wasm = TargetPlatform::parse("wasm32-unknown-unknown").unwrap();
+
+        assert!(linux.is_set("unix", None));
+        assert!(!linux.is_set("windows", None));
+        assert!(!linux.is_set("target_os", None));
+        assert!(linux.is_set("target_os", Some("linux")));
+        assert!(linux.is_set("target_family", Some("unix")));
+        assert!(!linux.is_set("target_os", Some("macos")));
+        assert!(!linux.is_set("unknown", Some("linux")));
+        assert!(!wasm.is_set("target_family", Some("")));
+    }
+
+    #[test]
+    fn as_str() {
+        let value = "x86_64-unknown-linux-gnu";
+        let result = TargetPlatform::parse(value).unwrap();
+        assert_eq!(value, result.as_str());
+    }
+
+    #[test]
+    fn parse() {
+        for platform in TargetPlatform::all() {
+            assert_eq!(
+                Ok(platform.clone()),
+                TargetPlatform::parse(platform.as_str())
+            );
+        }
+
+        assert_eq!(
+            Err("sparc-unknown-unknown is not a supported target.".to_owned()),
+            TargetPlatform::parse("sparc-unknown-unknown")
+        );
+    }
+}
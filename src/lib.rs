@@ -1,7 +1,9 @@
+mod advisory_database;
 mod configuration;
 mod crates_io_client;
 mod domain;
 mod postgres_client;
+mod resolver;
 mod routes;
 mod startup;
 pub mod telemetry;
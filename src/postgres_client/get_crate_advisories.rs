@@ -0,0 +1,155 @@
+use crate::domain::{CrateAdvisory, CrateAdvisorySeverity, CrateName, CrateRequirement};
+use crate::postgres_client::PostgresClient;
+use crate::telemetry::TraceErrorExt;
+use std::convert::TryFrom;
+
+impl PostgresClient {
+    #[tracing::instrument(
+        skip(self, names),
+        fields(
+            crate_names = %names.len(),
+        ),
+    )]
+    pub async fn get_crate_advisories(
+        &self,
+        names: &[CrateName],
+    ) -> Result<Vec<CrateAdvisory>, sqlx::Error> {
+        let crate_names = names
+            .iter()
+            .map(|name| name.as_canonical_str().to_owned())
+            .collect::<Vec<_>>();
+        let results = sqlx::query!(
+            r#"
+SELECT ca.id            AS crate_advisory_id,
+       ca.name          AS crate_advisory_name,
+       ca.severity      AS "crate_advisory_severity?",
+       ca.informational AS "crate_advisory_informational?",
+       ca.patched       AS crate_advisory_patched,
+       ca.unaffected    AS crate_advisory_unaffected
+FROM crate_advisory as ca
+WHERE ca.name = ANY ($1)
+ORDER BY ca.id;
+"#,
+            &crate_names[..],
+        )
+        .fetch_all(&self.pool)
+        .await
+        .trace_err()?;
+
+        let requirements = |values: Vec<String>| {
+            values
+                .iter()
+                .map(|value| CrateRequirement::parse(value))
+                .collect::<Result<_, _>>()
+        };
+
+        // Rows that no longer parse are traced and skipped rather than failing every lookup.
+        let result = results
+            .into_iter()
+            .map(|result| {
+                Ok(CrateAdvisory {
+                    id: result.crate_advisory_id,
+                    name: CrateName::parse(&result.crate_advisory_name)?,
+                    severity: result
+                        .crate_advisory_severity
+                        .as_deref()
+                        .map(CrateAdvisorySeverity::try_from)
+                        .transpose()?,
+                    informational: result.crate_advisory_informational,
+                    patched: requirements(result.crate_advisory_patched)?,
+                    unaffected: requirements(result.crate_advisory_unaffected)?,
+                    withdrawn: false,
+                })
+            })
+            .filter_map(|result: Result<CrateAdvisory, String>| result.trace_err().ok())
+            .collect();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{name, requirement};
+    use crate::postgres_client::tests::spawn_database;
+    use sqlx::{Pool, Postgres};
+
+    #[actix_rt::test]
+    async fn returns_empty_when_not_present() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_advisories(&[name("not-present")])
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn returns_advisories() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_advisories(&[name("Two_Advisories"), name("not-present")])
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(2, result.len());
+        assert_eq!("RUSTSEC-2021-0001", result[0].id);
+        assert_eq!(name("two-advisories"), result[0].name);
+        assert_eq!(Some(CrateAdvisorySeverity::Critical), result[0].severity);
+        assert_eq!(None, result[0].informational);
+        assert_eq!(vec![requirement(">= 1.6.1")], result[0].patched);
+        assert_eq!(vec![requirement("< 0.6.3")], result[0].unaffected);
+        assert_eq!("RUSTSEC-2021-0002", result[1].id);
+        assert_eq!(None, result[1].severity);
+        assert_eq!(Some("unmaintained".to_owned()), result[1].informational);
+        assert!(result[1].patched.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn skips_advisories_that_cannot_be_parsed() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_advisories(&[name("unparseable"), name("other")])
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(1, result.len());
+        assert_eq!("RUSTSEC-2021-0003", result[0].id);
+    }
+
+    async fn seed_database(database_pool: &Pool<Postgres>) {
+        sqlx::query(
+            r#"
+INSERT INTO crate_advisory (id, name, severity, informational, patched, unaffected)
+VALUES ('RUSTSEC-2021-0002', 'two-advisories', null, 'unmaintained', '{}', '{}'),
+       ('RUSTSEC-2021-0001', 'two-advisories', 'critical', null, '{">= 1.6.1"}', '{"< 0.6.3"}'),
+       ('RUSTSEC-2021-0003', 'other', 'low', null, '{}', '{}'),
+       ('RUSTSEC-2021-0004', 'unparseable', 'unknown', null, '{}', '{}'),
+       ('RUSTSEC-2021-0005', 'unparseable', null, null, '{"latest"}', '{}');
+"#,
+        )
+        .execute(database_pool)
+        .await
+        .unwrap();
+    }
+}
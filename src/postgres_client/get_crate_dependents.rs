@@ -0,0 +1,171 @@
+use crate::domain::{
+    CrateDependency, CrateDependencyType, CrateDependent, CrateName, CrateRequirement, CrateTarget,
+    CrateVersion,
+};
+use crate::postgres_client::PostgresClient;
+use crate::telemetry::TraceErrorExt;
+use std::convert::TryFrom;
+
+impl PostgresClient {
+    /// Returns the cached crate versions that depend on `name`, which only covers the crates this
+    /// service has resolved before.
+    #[tracing::instrument(
+        skip(self, name),
+        fields(
+            crate_name = %name.as_str(),
+        ),
+    )]
+    pub async fn get_crate_dependents(
+        &self,
+        name: &CrateName,
+    ) -> Result<Vec<CrateDependent>, sqlx::Error> {
+        let crate_name = name.as_canonical_str();
+        let results = sqlx::query!(
+            r#"
+SELECT cm.name             AS crate_metadata_name,
+       cm.version          AS crate_metadata_version,
+       cd.name             AS crate_dependency_name,
+       cd.requirement      AS crate_dependency_requirement,
+       cd.type             AS crate_dependency_type,
+       cd.optional         AS crate_dependency_optional,
+       cd.default_features AS crate_dependency_default_features,
+       cd.features         AS crate_dependency_features,
+       cd.target           AS "crate_dependency_target?"
+FROM crate_dependency as cd
+         JOIN crate_metadata cm on cm.id = cd.crate_id
+WHERE lower(replace(cd.name, '_', '-')) = $1
+ORDER BY cm.name, cm.version, cd.position;
+"#,
+            crate_name,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .trace_err()?;
+
+        let result = results
+            .into_iter()
+            .map(|result| CrateDependent {
+                name: CrateName::parse(&result.crate_metadata_name).unwrap(),
+                version: CrateVersion::parse(&result.crate_metadata_version).unwrap(),
+                dependency: CrateDependency {
+                    name: CrateName::parse(&result.crate_dependency_name).unwrap(),
+                    requirement: CrateRequirement::parse(&result.crate_dependency_requirement)
+                        .unwrap(),
+                    type_: CrateDependencyType::try_from(result.crate_dependency_type.as_str())
+                        .unwrap(),
+                    optional: result.crate_dependency_optional,
+                    default_features: result.crate_dependency_default_features,
+                    features: result.crate_dependency_features,
+                    target: result
+                        .crate_dependency_target
+                        .map(|target| CrateTarget::parse(&target).unwrap()),
+                },
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{dependency, name, version};
+    use crate::postgres_client::tests::spawn_database;
+    use sqlx::{Pool, Postgres};
+
+    #[actix_rt::test]
+    async fn returns_empty_when_not_present() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_dependents(&name("not-present"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn returns_dependents() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_dependents(&name("Unicode_Xid"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            vec![
+                CrateDependent {
+                    name: name("proc-macro2"),
+                    version: version("1.0.24"),
+                    dependency: dependency("unicode-xid", "^0.2", CrateDependencyType::Normal),
+                },
+                CrateDependent {
+                    name: name("syn"),
+                    version: version("0.11.11"),
+                    dependency: dependency("unicode_xid", "^0.0.4", CrateDependencyType::Normal),
+                },
+            ],
+            result
+        );
+    }
+
+    async fn seed_database(database_pool: &Pool<Postgres>) {
+        for (crate_name, crate_version, dependencies) in &[
+            (
+                "proc-macro2",
+                "1.0.24",
+                &[("quote", "^1.0", "dev"), ("unicode-xid", "^0.2", "normal")][..],
+            ),
+            ("syn", "0.11.11", &[("unicode_xid", "^0.0.4", "normal")][..]),
+            (
+                "quote",
+                "1.0.9",
+                &[("proc-macro2", "^1.0.20", "normal")][..],
+            ),
+        ] {
+            let i: i32 = sqlx::query_scalar(
+                r#"
+INSERT INTO crate_metadata (name, version, dependencies)
+VALUES ($1, $2, $3) RETURNING id;
+"#,
+            )
+            .bind(*crate_name)
+            .bind(*crate_version)
+            .bind(dependencies.len() as i32)
+            .fetch_one(database_pool)
+            .await
+            .unwrap();
+
+            for (position, (dependency_name, requirement, type_)) in dependencies.iter().enumerate()
+            {
+                sqlx::query(
+                    r#"
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type)
+VALUES ($1, $2, $3, $4, $5);
+"#,
+                )
+                .bind(i)
+                .bind(position as i32)
+                .bind(*dependency_name)
+                .bind(*requirement)
+                .bind(*type_)
+                .execute(database_pool)
+                .await
+                .unwrap();
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::domain::{
-    CrateDependency, CrateDependencyType, CrateMetadata, CrateName, CrateRequirement, CrateVersion,
+    CrateDependency, CrateDependencyType, CrateMetadata, CrateName, CrateRequirement, CrateTarget,
+    CrateVersion,
 };
 use crate::postgres_client::PostgresClient;
 use crate::telemetry::TraceErrorExt;
@@ -18,20 +19,25 @@ impl PostgresClient {
         name: &CrateName,
         version: &CrateVersion,
     ) -> Result<Option<CrateMetadata>, sqlx::Error> {
-        let crate_name = name.as_str();
+        let crate_name = name.as_canonical_str();
         let crate_version = version.as_str();
         let results = sqlx::query!(
             r#"
-SELECT cm.name         AS crate_metadata_name,
-       cm.version      AS crate_metadata_version,
-       cm.dependencies AS crate_metadata_dependencies,
-       cd.name         AS "crate_dependency_name?",
-       cd.requirement  AS "crate_dependency_requirement?",
-       cd.type         AS "crate_dependency_type?"
+SELECT cm.name             AS crate_metadata_name,
+       cm.version          AS crate_metadata_version,
+       cm.dependencies     AS crate_metadata_dependencies,
+       cd.name             AS "crate_dependency_name?",
+       cd.requirement      AS "crate_dependency_requirement?",
+       cd.type             AS "crate_dependency_type?",
+       cd.optional         AS "crate_dependency_optional?",
+       cd.default_features AS "crate_dependency_default_features?",
+       cd.features         AS "crate_dependency_features?",
+       cd.target           AS "crate_dependency_target?"
 FROM crate_metadata as cm
          LEFT JOIN crate_dependency cd on cm.id = cd.crate_id
 WHERE cm.name = $1
-  AND cm.version = $2;
+  AND cm.version = $2
+ORDER BY cd.position;
 "#,
             crate_name,
             crate_version,
@@ -71,10 +77,18 @@ WHERE cm.name = $1
                     let name = result.crate_dependency_name.as_ref().unwrap();
                     let requirement = result.crate_dependency_requirement.as_ref().unwrap();
                     let type_ = result.crate_dependency_type.as_ref().unwrap();
+                    let optional = result.crate_dependency_optional.unwrap();
+                    let default_features = result.crate_dependency_default_features.unwrap();
+                    let features = result.crate_dependency_features.as_ref().unwrap();
+                    let target = result.crate_dependency_target.as_ref();
                     CrateDependency {
                         name: CrateName::parse(&name).unwrap(),
                         requirement: CrateRequirement::parse(&requirement).unwrap(),
                         type_: CrateDependencyType::try_from(type_.as_ref()).unwrap(),
+                        optional,
+                        default_features,
+                        features: features.clone(),
+                        target: target.map(|target| CrateTarget::parse(target).unwrap()),
                     }
                 })
                 .collect(),
@@ -87,7 +101,8 @@ WHERE cm.name = $1
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::postgres_client::tests::{name, requirement, spawn_database, version};
+    use crate::domain::test_support::{dependency, name, target, version};
+    use crate::postgres_client::tests::spawn_database;
     use sqlx::{Pool, Postgres};
 
     #[actix_rt::test]
@@ -99,7 +114,7 @@ mod tests {
 
         // Act
         let result = client
-            .get_crate_metadata(&name("not-present"), &version("version-1"))
+            .get_crate_metadata(&name("not-present"), &version("1.0.0"))
             .await
             .unwrap();
 
@@ -116,7 +131,7 @@ mod tests {
 
         // Act
         let result = client
-            .get_crate_metadata(&name("corrupted"), &version("version-1"))
+            .get_crate_metadata(&name("corrupted"), &version("1.0.0"))
             .await
             .unwrap();
 
@@ -133,7 +148,7 @@ mod tests {
 
         // Act
         let result = client
-            .get_crate_metadata(&name("no-dependencies"), &version("version-1"))
+            .get_crate_metadata(&name("no-dependencies"), &version("1.0.0"))
             .await
             .unwrap()
             .unwrap();
@@ -142,13 +157,30 @@ mod tests {
         assert_eq!(
             CrateMetadata {
                 name: name("no-dependencies"),
-                version: version("version-1"),
+                version: version("1.0.0"),
                 dependencies: vec![]
             },
             result
         );
     }
 
+    #[actix_rt::test]
+    async fn returns_metadata_for_equivalent_name() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_metadata(&name("No_Dependencies"), &version("1.0.0"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_some());
+    }
+
     #[actix_rt::test]
     async fn returns_dependencies() {
         // Arrange
@@ -158,7 +190,7 @@ mod tests {
 
         // Act
         let result = client
-            .get_crate_metadata(&name("three-dependencies"), &version("version-1"))
+            .get_crate_metadata(&name("three-dependencies"), &version("1.0.0"))
             .await;
 
         // Assert
@@ -168,31 +200,51 @@ mod tests {
             result,
             CrateMetadata {
                 name: name("three-dependencies"),
-                version: version("version-1"),
+                version: version("1.0.0"),
                 dependencies: vec![
-                    CrateDependency {
-                        name: name("name-1"),
-                        requirement: requirement("requirement-1"),
-                        type_: CrateDependencyType::Build
-                    },
-                    CrateDependency {
-                        name: name("name-2"),
-                        requirement: requirement("requirement-2"),
-                        type_: CrateDependencyType::Dev
-                    },
-                    CrateDependency {
-                        name: name("name-3"),
-                        requirement: requirement("requirement-3"),
-                        type_: CrateDependencyType::Normal
-                    }
+                    dependency("name-1", "^1.0.0", CrateDependencyType::Build),
+                    dependency("name-2", "^2.0.0", CrateDependencyType::Dev),
+                    dependency("name-3", "^3.0.0", CrateDependencyType::Normal)
                 ]
             }
         );
     }
 
+    #[actix_rt::test]
+    async fn returns_dependency_features_and_target() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_metadata(&name("features-and-target"), &version("1.0.0"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            CrateMetadata {
+                name: name("features-and-target"),
+                version: version("1.0.0"),
+                dependencies: vec![CrateDependency {
+                    optional: true,
+                    default_features: false,
+                    features: vec!["derive".to_owned(), "std".to_owned()],
+                    target: Some(target("cfg(unix)")),
+                    ..dependency("name-1", "^1.0.0", CrateDependencyType::Normal)
+                }]
+            },
+            result
+        );
+    }
+
     async fn seed_database(database_pool: &Pool<Postgres>) {
         seed_no_dependencies(database_pool).await;
         seed_three_dependencies(database_pool).await;
+        seed_features_and_target(database_pool).await;
         seed_corrupted(database_pool).await;
     }
 
@@ -200,7 +252,7 @@ mod tests {
         sqlx::query(
             r#"
 INSERT INTO crate_metadata (name, version, dependencies)
-VALUES ('no-dependencies', 'version-1', 0);
+VALUES ('no-dependencies', '1.0.0', 0);
 "#,
         )
         .execute(database_pool)
@@ -212,7 +264,32 @@ VALUES ('no-dependencies', 'version-1', 0);
         let i: i32 = sqlx::query_scalar(
             r#"
 INSERT INTO crate_metadata (name, version, dependencies)
-VALUES ('three-dependencies', 'version-1', 3) RETURNING id;
+VALUES ('three-dependencies', '1.0.0', 3) RETURNING id;
+"#,
+        )
+        .fetch_one(database_pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type)
+VALUES ($1, 0, 'name-1', '^1.0.0', 'build'),
+       ($1, 1, 'name-2', '^2.0.0', 'dev'),
+       ($1, 2, 'name-3', '^3.0.0', 'normal');
+"#,
+        )
+        .bind(i)
+        .execute(database_pool)
+        .await
+        .unwrap();
+    }
+
+    async fn seed_features_and_target(database_pool: &Pool<Postgres>) {
+        let i: i32 = sqlx::query_scalar(
+            r#"
+INSERT INTO crate_metadata (name, version, dependencies)
+VALUES ('features-and-target', '1.0.0', 1) RETURNING id;
 "#,
         )
         .fetch_one(database_pool)
@@ -221,10 +298,8 @@ VALUES ('three-dependencies', 'version-1', 3) RETURNING id;
 
         sqlx::query(
             r#"
-INSERT INTO crate_dependency (crate_id, name, requirement, type)
-VALUES ($1, 'name-1', 'requirement-1', 'build'),
-       ($1, 'name-2', 'requirement-2', 'dev'),
-       ($1, 'name-3', 'requirement-3', 'normal');
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type, optional, default_features, features, target)
+VALUES ($1, 0, 'name-1', '^1.0.0', 'normal', true, false, '{derive,std}', 'cfg(unix)');
 "#,
         )
         .bind(i)
@@ -237,7 +312,7 @@ VALUES ($1, 'name-1', 'requirement-1', 'build'),
         let i: i32 = sqlx::query_scalar(
             r#"
 INSERT INTO crate_metadata (name, version, dependencies)
-VALUES ('corrupted', 'version-1', 3) RETURNING id;
+VALUES ('corrupted', '1.0.0', 3) RETURNING id;
 "#,
         )
         .fetch_one(database_pool)
@@ -246,8 +321,8 @@ VALUES ('corrupted', 'version-1', 3) RETURNING id;
 
         sqlx::query(
             r#"
-INSERT INTO crate_dependency (crate_id, name, requirement, type)
-VALUES ($1, 'name-1', 'requirement-1', 'build');
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type)
+VALUES ($1, 0, 'name-1', '^1.0.0', 'build');
 "#,
         )
         .bind(i)
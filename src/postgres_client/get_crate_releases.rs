@@ -0,0 +1,213 @@
+use crate::domain::{CrateFeatures, CrateName, CrateRelease, CrateVersion};
+use crate::postgres_client::PostgresClient;
+use crate::telemetry::TraceErrorExt;
+
+impl PostgresClient {
+    /// Releases fetched more than an hour ago are ignored, since versions are published and
+    /// yanked after the fact. Releases are only returned when every row agrees with the number
+    /// of releases saved alongside it, so rows left over from an older save are not mistaken for
+    /// the complete list. Newest releases come first.
+    #[tracing::instrument(
+        skip(self, name),
+        fields(
+            crate_name = %name.as_str(),
+        ),
+    )]
+    pub async fn get_crate_releases(
+        &self,
+        name: &CrateName,
+    ) -> Result<Option<Vec<CrateRelease>>, sqlx::Error> {
+        let crate_name = name.as_canonical_str();
+        let results = sqlx::query!(
+            r#"
+SELECT cv.version      AS crate_version_version,
+       cv.created_at   AS crate_version_created_at,
+       cv.yanked       AS crate_version_yanked,
+       cv.checksum     AS crate_version_checksum,
+       cv.rust_version AS "crate_version_rust_version?",
+       cv.features     AS crate_version_features,
+       cv.license      AS "crate_version_license?",
+       cv.releases     AS crate_version_releases
+FROM crate_version as cv
+WHERE cv.name = $1
+  AND cv.fetched_at > now() - interval '1 hour'
+ORDER BY cv.created_at DESC;
+"#,
+            crate_name,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .trace_err()?;
+
+        if results.is_empty() {
+            return Ok(None);
+        }
+
+        if results
+            .iter()
+            .any(|result| result.crate_version_releases as usize != results.len())
+        {
+            tracing::warn!("checksum failed");
+            return Ok(None);
+        }
+
+        let result = results
+            .into_iter()
+            .map(|result| CrateRelease {
+                version: CrateVersion::parse(&result.crate_version_version).unwrap(),
+                created_at: result.crate_version_created_at,
+                yanked: result.crate_version_yanked,
+                checksum: result.crate_version_checksum,
+                rust_version: result.crate_version_rust_version,
+                license: result.crate_version_license,
+                features: CrateFeatures::new(
+                    serde_json::from_value(result.crate_version_features).unwrap(),
+                ),
+            })
+            .collect();
+
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{name, version};
+    use crate::postgres_client::tests::spawn_database;
+    use sqlx::{Pool, Postgres};
+
+    #[actix_rt::test]
+    async fn returns_none_when_not_present() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_releases(&name("not-present"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn returns_releases() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let mut result = client
+            .get_crate_releases(&name("Two_Releases"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        result.sort_by(|a, b| a.version.cmp(&b.version));
+
+        assert_eq!(2, result.len());
+        assert_eq!(version("1.0.0"), result[0].version);
+        assert_eq!(
+            "2021-01-01T00:00:00+00:00",
+            result[0].created_at.to_rfc3339()
+        );
+        assert!(result[0].yanked);
+        assert_eq!("checksum-1", result[0].checksum);
+        assert_eq!(None, result[0].rust_version);
+        assert_eq!(None, result[0].license);
+        assert!(result[0].features.as_map().is_empty());
+        assert_eq!(version("1.1.0"), result[1].version);
+        assert!(!result[1].yanked);
+        assert_eq!(Some("1.46".to_owned()), result[1].rust_version);
+        assert_eq!(Some("MIT OR Apache-2.0".to_owned()), result[1].license);
+        assert_eq!(
+            Some(&vec!["std".to_owned()]),
+            result[1].features.as_map().get("default")
+        );
+    }
+
+    #[actix_rt::test]
+    async fn returns_newest_releases_first() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_releases(&name("two-releases"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            vec![version("1.1.0"), version("1.0.0")],
+            result
+                .into_iter()
+                .map(|release| release.version)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn returns_none_when_stale() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client.get_crate_releases(&name("stale")).await.unwrap();
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn returns_none_when_incomplete() {
+        // Arrange
+        let pool = spawn_database().await;
+        seed_database(&pool).await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        let result = client
+            .get_crate_releases(&name("incomplete"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    async fn seed_database(database_pool: &Pool<Postgres>) {
+        sqlx::query(
+            r#"
+INSERT INTO crate_version (name, version, created_at, yanked, checksum, releases, fetched_at)
+VALUES ('stale', '1.0.0', '2021-01-01T00:00:00Z', false, 'checksum-1', 1, now() - interval '2 hours');
+"#,
+        )
+        .execute(database_pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+INSERT INTO crate_version (name, version, created_at, yanked, checksum, rust_version, features, license, releases)
+VALUES ('two-releases', '1.0.0', '2021-01-01T00:00:00Z', true, 'checksum-1', null, '{}', null, 2),
+       ('two-releases', '1.1.0', '2021-02-01T00:00:00Z', false, 'checksum-2', '1.46', '{"default": ["std"], "std": []}', 'MIT OR Apache-2.0', 2),
+       ('incomplete', '1.0.0', '2021-01-01T00:00:00Z', false, 'checksum-1', null, '{}', null, 2);
+"#,
+        )
+        .execute(database_pool)
+        .await
+        .unwrap();
+    }
+}
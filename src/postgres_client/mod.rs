@@ -1,6 +1,11 @@
 use sqlx::{Pool, Postgres};
+mod get_crate_advisories;
+mod get_crate_dependents;
 mod get_crate_metadata;
+mod get_crate_releases;
+mod save_crate_advisories;
 mod save_crate_metadata;
+mod save_crate_releases;
 
 pub struct PostgresClient {
     pool: Pool<Postgres>,
@@ -19,8 +24,6 @@ mod tests {
 
     use crate::configuration::Configuration;
 
-    use crate::domain::{CrateName, CrateRequirement, CrateVersion};
-
     pub async fn spawn_database() -> Pool<Postgres> {
         let mut configuration = Configuration::load(&[]).unwrap();
         configuration.postgres.database_name = format!("test-{}", Uuid::new_v4().to_string());
@@ -44,16 +47,4 @@ mod tests {
 
         database_pool
     }
-
-    pub fn name(value: &str) -> CrateName {
-        CrateName::parse(value).unwrap()
-    }
-
-    pub fn requirement(value: &str) -> CrateRequirement {
-        CrateRequirement::parse(value).unwrap()
-    }
-
-    pub fn version(value: &str) -> CrateVersion {
-        CrateVersion::parse(value).unwrap()
-    }
 }
@@ -0,0 +1,160 @@
+use crate::domain::CrateAdvisory;
+use crate::postgres_client::PostgresClient;
+use crate::telemetry::TraceErrorExt;
+
+impl PostgresClient {
+    /// Replaces the stored advisories, so advisories removed from the database are dropped.
+    #[tracing::instrument(
+        skip(self, advisories),
+        fields(
+            crate_advisories = %advisories.len(),
+        ),
+    )]
+    pub async fn save_crate_advisories(
+        &self,
+        advisories: &[CrateAdvisory],
+    ) -> Result<(), sqlx::Error> {
+        for advisory in advisories {
+            let crate_advisory_id = advisory.id.as_str();
+            let crate_advisory_name = advisory.name.as_canonical_str();
+            let crate_advisory_severity =
+                advisory.severity.as_ref().map(|severity| severity.as_str());
+            let crate_advisory_informational = advisory.informational.as_deref();
+            let crate_advisory_patched = advisory
+                .patched
+                .iter()
+                .map(|requirement| requirement.as_str().to_owned())
+                .collect::<Vec<_>>();
+            let crate_advisory_unaffected = advisory
+                .unaffected
+                .iter()
+                .map(|requirement| requirement.as_str().to_owned())
+                .collect::<Vec<_>>();
+            sqlx::query!(
+                r#"
+INSERT INTO crate_advisory (id, name, severity, informational, patched, unaffected)
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (id) DO UPDATE
+    SET name          = EXCLUDED.name,
+        severity      = EXCLUDED.severity,
+        informational = EXCLUDED.informational,
+        patched       = EXCLUDED.patched,
+        unaffected    = EXCLUDED.unaffected;
+"#,
+                crate_advisory_id,
+                crate_advisory_name,
+                crate_advisory_severity,
+                crate_advisory_informational,
+                &crate_advisory_patched[..],
+                &crate_advisory_unaffected[..]
+            )
+            .execute(&self.pool)
+            .await
+            .trace_err()?;
+        }
+
+        let crate_advisory_ids = advisories
+            .iter()
+            .map(|advisory| advisory.id.clone())
+            .collect::<Vec<_>>();
+        sqlx::query!(
+            r#"
+DELETE
+FROM crate_advisory
+WHERE NOT (id = ANY ($1));
+"#,
+            &crate_advisory_ids[..]
+        )
+        .execute(&self.pool)
+        .await
+        .trace_err()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{name, requirement};
+    use crate::domain::CrateAdvisorySeverity;
+    use crate::postgres_client::tests::spawn_database;
+
+    #[actix_rt::test]
+    async fn saves_advisories() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        let advisories = vec![
+            CrateAdvisory {
+                severity: Some(CrateAdvisorySeverity::High),
+                patched: vec![requirement(">= 1.6.1")],
+                unaffected: vec![requirement("< 0.6.3")],
+                ..advisory("RUSTSEC-2021-0001", "first")
+            },
+            CrateAdvisory {
+                informational: Some("unmaintained".to_owned()),
+                ..advisory("RUSTSEC-2021-0002", "second")
+            },
+        ];
+
+        // Act
+        client.save_crate_advisories(&advisories).await.unwrap();
+
+        // Assert
+        let result = client
+            .get_crate_advisories(&[name("first"), name("second")])
+            .await
+            .unwrap();
+
+        assert_eq!(advisories, result);
+    }
+
+    #[actix_rt::test]
+    async fn saves_advisories_when_advisories_were_removed() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        client
+            .save_crate_advisories(&[
+                advisory("RUSTSEC-2021-0001", "first"),
+                advisory("RUSTSEC-2021-0002", "first"),
+            ])
+            .await
+            .unwrap();
+
+        // Act
+        client
+            .save_crate_advisories(&[CrateAdvisory {
+                severity: Some(CrateAdvisorySeverity::Low),
+                ..advisory("RUSTSEC-2021-0002", "first")
+            }])
+            .await
+            .unwrap();
+
+        // Assert
+        let result = client.get_crate_advisories(&[name("first")]).await.unwrap();
+
+        assert_eq!(
+            vec![CrateAdvisory {
+                severity: Some(CrateAdvisorySeverity::Low),
+                ..advisory("RUSTSEC-2021-0002", "first")
+            }],
+            result
+        );
+    }
+
+    fn advisory(id: &str, name_value: &str) -> CrateAdvisory {
+        CrateAdvisory {
+            id: id.to_owned(),
+            name: name(name_value),
+            severity: None,
+            informational: None,
+            patched: vec![],
+            unaffected: vec![],
+            withdrawn: false,
+        }
+    }
+}
@@ -14,7 +14,7 @@ impl PostgresClient {
         &self,
         crate_metadata: &CrateMetadata,
     ) -> Result<(), sqlx::Error> {
-        let crate_name = crate_metadata.name.as_str();
+        let crate_name = crate_metadata.name.as_canonical_str();
         let crate_version = crate_metadata.version.as_str();
         let crate_dependencies = crate_metadata.dependencies.len() as i32;
         let row = sqlx::query!(
@@ -33,20 +33,52 @@ RETURNING id;
         .await
         .trace_err()?;
 
-        for dependency in &crate_metadata.dependencies {
+        sqlx::query!(
+            r#"
+DELETE
+FROM crate_dependency
+WHERE crate_id = $1
+  AND position >= $2;
+"#,
+            row.id,
+            crate_dependencies
+        )
+        .execute(&self.pool)
+        .await
+        .trace_err()?;
+
+        for (position, dependency) in crate_metadata.dependencies.iter().enumerate() {
+            let crate_dependency_position = position as i32;
             let crate_metadata_name = dependency.name.as_str();
             let crate_metadata_requirement = dependency.requirement.as_str();
             let crate_dependency_type = dependency.type_.as_str();
+            let crate_dependency_optional = dependency.optional;
+            let crate_dependency_default_features = dependency.default_features;
+            let crate_dependency_features = &dependency.features[..];
+            let crate_dependency_target =
+                dependency.target.as_ref().map(|target| target.to_string());
             sqlx::query!(
                 r#"
-INSERT INTO crate_dependency (crate_id, name, requirement, type)
-VALUES ($1, $2, $3, $4)
-ON CONFLICT (name, type, crate_id) DO UPDATE SET requirement = EXCLUDED.requirement;
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type, optional, default_features, features, target)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (crate_id, position) DO UPDATE
+    SET name             = EXCLUDED.name,
+        requirement      = EXCLUDED.requirement,
+        type             = EXCLUDED.type,
+        optional         = EXCLUDED.optional,
+        default_features = EXCLUDED.default_features,
+        features         = EXCLUDED.features,
+        target           = EXCLUDED.target;
 "#,
                 row.id,
+                crate_dependency_position,
                 crate_metadata_name,
                 crate_metadata_requirement,
-                crate_dependency_type
+                crate_dependency_type,
+                crate_dependency_optional,
+                crate_dependency_default_features,
+                crate_dependency_features,
+                crate_dependency_target.as_deref()
             )
             .execute(&self.pool)
             .await
@@ -60,8 +92,9 @@ ON CONFLICT (name, type, crate_id) DO UPDATE SET requirement = EXCLUDED.requirem
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::test_support::{dependency, name, target, version};
     use crate::domain::{CrateDependency, CrateDependencyType};
-    use crate::postgres_client::tests::{name, requirement, spawn_database, version};
+    use crate::postgres_client::tests::spawn_database;
     use sqlx::{Pool, Postgres, Row};
 
     #[actix_rt::test]
@@ -74,7 +107,7 @@ mod tests {
         client
             .save_crate_metadata(&CrateMetadata {
                 name: name("no-dependencies"),
-                version: version("version-1"),
+                version: version("1.0.0"),
                 dependencies: vec![],
             })
             .await
@@ -82,10 +115,10 @@ mod tests {
 
         // Assert
         assert(
-            &[("no-dependencies", "version-1", 0, None, None, None)],
+            &[("no-dependencies", "1.0.0", 0, None, None, None)],
             &pool,
             "no-dependencies",
-            "version-1",
+            "1.0.0",
         )
         .await
     }
@@ -100,23 +133,11 @@ mod tests {
         client
             .save_crate_metadata(&CrateMetadata {
                 name: name("three-dependencies"),
-                version: version("version-1"),
+                version: version("1.0.0"),
                 dependencies: vec![
-                    CrateDependency {
-                        name: name("name-1"),
-                        requirement: requirement("requirement-1"),
-                        type_: CrateDependencyType::Build,
-                    },
-                    CrateDependency {
-                        name: name("name-2"),
-                        requirement: requirement("requirement-2"),
-                        type_: CrateDependencyType::Dev,
-                    },
-                    CrateDependency {
-                        name: name("name-3"),
-                        requirement: requirement("requirement-3"),
-                        type_: CrateDependencyType::Normal,
-                    },
+                    dependency("name-1", "^1.0.0", CrateDependencyType::Build),
+                    dependency("name-2", "^2.0.0", CrateDependencyType::Dev),
+                    dependency("name-3", "^3.0.0", CrateDependencyType::Normal),
                 ],
             })
             .await
@@ -127,32 +148,32 @@ mod tests {
             &vec![
                 (
                     "three-dependencies",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-1"),
-                    Some("requirement-1"),
+                    Some("^1.0.0"),
                     Some("build"),
                 ),
                 (
                     "three-dependencies",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-2"),
-                    Some("requirement-2"),
+                    Some("^2.0.0"),
                     Some("dev"),
                 ),
                 (
                     "three-dependencies",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-3"),
-                    Some("requirement-3"),
+                    Some("^3.0.0"),
                     Some("normal"),
                 ),
             ],
             &pool,
             "three-dependencies",
-            "version-1",
+            "1.0.0",
         )
         .await
     }
@@ -166,7 +187,7 @@ mod tests {
         let i: i32 = sqlx::query_scalar(
             r#"
 INSERT INTO crate_metadata (name, version, dependencies)
-VALUES ('three-dependencies-corrupted', 'version-1', 2) RETURNING id;
+VALUES ('three-dependencies-corrupted', '1.0.0', 2) RETURNING id;
 "#,
         )
         .fetch_one(&pool)
@@ -175,8 +196,8 @@ VALUES ('three-dependencies-corrupted', 'version-1', 2) RETURNING id;
 
         sqlx::query(
             r#"
-INSERT INTO crate_dependency (crate_id, name, requirement, type)
-VALUES ($1, 'name-1', 'wrong-requirement-1', 'build');
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type)
+VALUES ($1, 0, 'name-1', '^0.1.0', 'build');
 "#,
         )
         .bind(i)
@@ -188,23 +209,11 @@ VALUES ($1, 'name-1', 'wrong-requirement-1', 'build');
         client
             .save_crate_metadata(&CrateMetadata {
                 name: name("three-dependencies-corrupted"),
-                version: version("version-1"),
+                version: version("1.0.0"),
                 dependencies: vec![
-                    CrateDependency {
-                        name: name("name-1"),
-                        requirement: requirement("requirement-1"),
-                        type_: CrateDependencyType::Build,
-                    },
-                    CrateDependency {
-                        name: name("name-2"),
-                        requirement: requirement("requirement-2"),
-                        type_: CrateDependencyType::Dev,
-                    },
-                    CrateDependency {
-                        name: name("name-3"),
-                        requirement: requirement("requirement-3"),
-                        type_: CrateDependencyType::Normal,
-                    },
+                    dependency("name-1", "^1.0.0", CrateDependencyType::Build),
+                    dependency("name-2", "^2.0.0", CrateDependencyType::Dev),
+                    dependency("name-3", "^3.0.0", CrateDependencyType::Normal),
                 ],
             })
             .await
@@ -215,36 +224,172 @@ VALUES ($1, 'name-1', 'wrong-requirement-1', 'build');
             &vec![
                 (
                     "three-dependencies-corrupted",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-1"),
-                    Some("requirement-1"),
+                    Some("^1.0.0"),
                     Some("build"),
                 ),
                 (
                     "three-dependencies-corrupted",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-2"),
-                    Some("requirement-2"),
+                    Some("^2.0.0"),
                     Some("dev"),
                 ),
                 (
                     "three-dependencies-corrupted",
-                    "version-1",
+                    "1.0.0",
                     3,
                     Some("name-3"),
-                    Some("requirement-3"),
+                    Some("^3.0.0"),
                     Some("normal"),
                 ),
             ],
             &pool,
             "three-dependencies-corrupted",
-            "version-1",
+            "1.0.0",
+        )
+        .await
+    }
+
+    #[actix_rt::test]
+    async fn saves_with_dependencies_when_persistence_has_stale_dependencies() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        let i: i32 = sqlx::query_scalar(
+            r#"
+INSERT INTO crate_metadata (name, version, dependencies)
+VALUES ('one-dependency-stale', '1.0.0', 2) RETURNING id;
+"#,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+INSERT INTO crate_dependency (crate_id, position, name, requirement, type)
+VALUES ($1, 0, 'name-1', '^0.1.0', 'build'),
+       ($1, 1, 'name-2', '^0.2.0', 'dev');
+"#,
+        )
+        .bind(i)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Act
+        client
+            .save_crate_metadata(&CrateMetadata {
+                name: name("one-dependency-stale"),
+                version: version("1.0.0"),
+                dependencies: vec![dependency("name-1", "^1.0.0", CrateDependencyType::Build)],
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert(
+            &[(
+                "one-dependency-stale",
+                "1.0.0",
+                1,
+                Some("name-1"),
+                Some("^1.0.0"),
+                Some("build"),
+            )],
+            &pool,
+            "one-dependency-stale",
+            "1.0.0",
         )
         .await
     }
 
+    #[actix_rt::test]
+    async fn saves_target_specific_duplicate_dependencies() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        let crate_metadata = CrateMetadata {
+            name: name("target-specific-duplicates"),
+            version: version("1.0.0"),
+            dependencies: vec![
+                CrateDependency {
+                    target: Some(target("cfg(windows)")),
+                    ..dependency("winapi", "^0.3.9", CrateDependencyType::Normal)
+                },
+                CrateDependency {
+                    target: Some(target("i686-pc-windows-gnu")),
+                    ..dependency("winapi", "^0.3.9", CrateDependencyType::Normal)
+                },
+                dependency("winapi", "^0.3.9", CrateDependencyType::Normal),
+            ],
+        };
+
+        // Act
+        client.save_crate_metadata(&crate_metadata).await.unwrap();
+
+        // Assert
+        let result = client
+            .get_crate_metadata(&name("target-specific-duplicates"), &version("1.0.0"))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(crate_metadata), result);
+    }
+
+    #[actix_rt::test]
+    async fn saves_dependency_features_and_target() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        // Act
+        client
+            .save_crate_metadata(&CrateMetadata {
+                name: name("features-and-target"),
+                version: version("1.0.0"),
+                dependencies: vec![CrateDependency {
+                    optional: true,
+                    default_features: false,
+                    features: vec!["derive".to_owned(), "std".to_owned()],
+                    target: Some(target("cfg(unix)")),
+                    ..dependency("name-1", "^1.0.0", CrateDependencyType::Normal)
+                }],
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        let row = sqlx::query(
+            r#"
+SELECT cd.optional,
+       cd.default_features,
+       cd.features,
+       cd.target
+FROM crate_metadata AS cm
+         JOIN crate_dependency AS cd on cm.id = cd.crate_id
+WHERE cm.name = 'features-and-target';
+        "#,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        assert!(row.get::<bool, _>("optional"));
+        assert!(!row.get::<bool, _>("default_features"));
+        assert_eq!(
+            vec!["derive".to_owned(), "std".to_owned()],
+            row.get::<Vec<String>, _>("features")
+        );
+        assert_eq!(Some("cfg(unix)"), row.get::<Option<&str>, _>("target"));
+    }
+
     #[allow(clippy::type_complexity)]
     async fn assert(
         data: &[(&str, &str, i32, Option<&str>, Option<&str>, Option<&str>)],
@@ -264,7 +409,8 @@ SELECT cm.id,
 FROM crate_metadata AS cm
          LEFT JOIN crate_dependency AS cd on cm.id = cd.crate_id
 WHERE cm.name = $1
-  AND cm.version = $2;
+  AND cm.version = $2
+ORDER BY cd.position;
         "#,
         )
         .bind(name)
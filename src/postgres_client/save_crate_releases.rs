@@ -0,0 +1,162 @@
+use crate::domain::{CrateName, CrateRelease};
+use crate::postgres_client::PostgresClient;
+use crate::telemetry::TraceErrorExt;
+
+impl PostgresClient {
+    /// Releases are saved in a single transaction, so the cache never holds part of a save.
+    #[tracing::instrument(
+        skip(self, name, releases),
+        fields(
+            crate_name = %name.as_str(),
+            crate_releases = %releases.len(),
+        ),
+    )]
+    pub async fn save_crate_releases(
+        &self,
+        name: &CrateName,
+        releases: &[CrateRelease],
+    ) -> Result<(), sqlx::Error> {
+        let crate_name = name.as_canonical_str();
+        let crate_version_releases = releases.len() as i32;
+
+        let mut transaction = self.pool.begin().await.trace_err()?;
+
+        for release in releases {
+            let crate_version_version = release.version.as_str();
+            let crate_version_created_at = release.created_at;
+            let crate_version_yanked = release.yanked;
+            let crate_version_checksum = release.checksum.as_str();
+            let crate_version_rust_version = release.rust_version.as_deref();
+            let crate_version_license = release.license.as_deref();
+            let crate_version_features = serde_json::to_value(release.features.as_map())
+                .expect("Failed to serialize features.");
+            sqlx::query!(
+                r#"
+INSERT INTO crate_version (name, version, created_at, yanked, checksum, rust_version, features, license, releases)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (name, version) DO UPDATE
+    SET created_at   = EXCLUDED.created_at,
+        yanked       = EXCLUDED.yanked,
+        checksum     = EXCLUDED.checksum,
+        rust_version = EXCLUDED.rust_version,
+        features     = EXCLUDED.features,
+        license      = EXCLUDED.license,
+        releases     = EXCLUDED.releases,
+        fetched_at   = now();
+"#,
+                crate_name,
+                crate_version_version,
+                crate_version_created_at,
+                crate_version_yanked,
+                crate_version_checksum,
+                crate_version_rust_version,
+                crate_version_features,
+                crate_version_license,
+                crate_version_releases
+            )
+            .execute(&mut transaction)
+            .await
+            .trace_err()?;
+        }
+
+        transaction.commit().await.trace_err()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::{name, release};
+    use crate::domain::CrateFeatures;
+    use crate::postgres_client::tests::spawn_database;
+    use sqlx::Row;
+
+    #[actix_rt::test]
+    async fn saves_releases() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        let releases = vec![
+            release("1.0.0", true),
+            CrateRelease {
+                rust_version: Some("1.46".to_owned()),
+                license: Some("MIT OR Apache-2.0".to_owned()),
+                features: CrateFeatures::new(
+                    vec![("default".to_owned(), vec!["std".to_owned()])]
+                        .into_iter()
+                        .collect(),
+                ),
+                ..release("1.1.0", false)
+            },
+        ];
+
+        // Act
+        client
+            .save_crate_releases(&name("Two_Releases"), &releases)
+            .await
+            .unwrap();
+
+        // Assert
+        let mut result = client
+            .get_crate_releases(&name("two-releases"))
+            .await
+            .unwrap()
+            .unwrap();
+        result.sort_by(|a, b| a.version.cmp(&b.version));
+
+        assert_eq!(releases, result);
+    }
+
+    #[actix_rt::test]
+    async fn saves_releases_when_persistence_is_stale() {
+        // Arrange
+        let pool = spawn_database().await;
+        let client = PostgresClient::new(pool.clone());
+
+        client
+            .save_crate_releases(&name("stale-release"), &[release("1.0.0", false)])
+            .await
+            .unwrap();
+
+        sqlx::query(
+            r#"
+UPDATE crate_version
+SET fetched_at = now() - interval '2 hours'
+WHERE name = 'stale-release';
+"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Act
+        client
+            .save_crate_releases(&name("stale-release"), &[release("1.0.0", true)])
+            .await
+            .unwrap();
+
+        // Assert
+        let rows = sqlx::query(
+            r#"
+SELECT cv.yanked
+FROM crate_version AS cv
+WHERE cv.name = 'stale-release';
+        "#,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(1, rows.len());
+        assert!(rows[0].get::<bool, _>("yanked"));
+
+        let result = client
+            .get_crate_releases(&name("stale-release"))
+            .await
+            .unwrap();
+        assert!(result.is_some());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::resolver::{DependencyEdge, DependencyGraph};
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyCycle {
+    /// Edges in the order they are followed, the last edge leads back to the first crate.
+    pub edges: Vec<DependencyEdge>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+impl DependencyGraph {
+    /// Finds the cycles through the resolved edges with a depth first search, which visits each
+    /// crate once and reports a cycle whenever an edge leads back to a crate still being visited.
+    pub fn cycles(&self) -> Vec<DependencyCycle> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges() {
+            adjacency[edge.from].push(edge);
+        }
+
+        let mut states = vec![State::Unvisited; self.nodes.len()];
+        let mut cycles = Vec::new();
+
+        for root in 0..self.nodes.len() {
+            if states[root] != State::Unvisited {
+                continue;
+            }
+
+            states[root] = State::InProgress;
+            let mut stack = vec![(root, 0)];
+            let mut path: Vec<DependencyEdge> = Vec::new();
+
+            while let Some(&(node, next)) = stack.last() {
+                let edge = match adjacency[node].get(next) {
+                    Some(edge) => *edge,
+                    None => {
+                        states[node] = State::Done;
+                        stack.pop();
+                        path.pop();
+                        continue;
+                    }
+                };
+
+                let top = stack.len() - 1;
+                stack[top].1 += 1;
+
+                match states[edge.to] {
+                    State::Unvisited => {
+                        states[edge.to] = State::InProgress;
+                        stack.push((edge.to, 0));
+                        path.push(edge);
+                    }
+                    State::InProgress => {
+                        let start = path
+                            .iter()
+                            .position(|visited| visited.from == edge.to)
+                            .unwrap_or(path.len());
+
+                        let mut edges = path[start..].to_vec();
+                        edges.push(edge);
+
+                        cycles.push(DependencyCycle { edges });
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+
+        cycles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolver::test_support::{graph, node};
+
+    #[test]
+    fn cycles_returns_empty_when_acyclic() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0"), ("c", "1.0.0")]),
+            node("b", "1.0.0", &[("c", "1.0.0")]),
+            node("c", "1.0.0", &[]),
+        ]);
+
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn cycles_returns_cycle_in_order() {
+        let mut b = node("b", "1.0.0", &[("d", "1.0.0"), ("c", "1.0.0")]);
+        b.resolved_versions[0] = None;
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0")]),
+            b,
+            node("c", "1.0.0", &[("b", "1.0.0")]),
+        ]);
+
+        assert_eq!(
+            vec![DependencyCycle {
+                edges: vec![
+                    DependencyEdge {
+                        from: 1,
+                        dependency: 1,
+                        to: 2
+                    },
+                    DependencyEdge {
+                        from: 2,
+                        dependency: 0,
+                        to: 1
+                    },
+                ]
+            }],
+            graph.cycles()
+        );
+    }
+
+    #[test]
+    fn cycles_returns_self_dependency() {
+        let graph = graph(vec![node("a", "1.0.0", &[("a", "1.0.0")])]);
+
+        assert_eq!(
+            vec![DependencyCycle {
+                edges: vec![DependencyEdge {
+                    from: 0,
+                    dependency: 0,
+                    to: 0
+                }]
+            }],
+            graph.cycles()
+        );
+    }
+
+    #[test]
+    fn cycles_ignores_other_versions() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0")]),
+            node("b", "1.0.0", &[("a", "2.0.0")]),
+        ]);
+
+        assert!(graph.cycles().is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+#[derive(Debug, PartialEq)]
+pub enum Depth {
+    Limited(usize),
+    Unlimited,
+}
+
+impl Depth {
+    pub fn allows(&self, level: usize) -> bool {
+        match self {
+            Depth::Limited(depth) => level <= *depth,
+            Depth::Unlimited => true,
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "all" => Ok(Self::Unlimited),
+            other => other.parse().map(Self::Limited).map_err(|_| {
+                format!(
+                    "{} is not a supported depth. Use either a number or `all`.",
+                    other
+                )
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows() {
+        assert!(Depth::Limited(0).allows(0));
+        assert!(!Depth::Limited(0).allows(1));
+        assert!(Depth::Limited(2).allows(2));
+        assert!(!Depth::Limited(2).allows(3));
+        assert!(Depth::Unlimited.allows(usize::MAX));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(Depth::Limited(0)), Depth::parse("0"));
+        assert_eq!(Ok(Depth::Limited(3)), Depth::parse("3"));
+        assert_eq!(Ok(Depth::Unlimited), Depth::parse("all"));
+        assert_eq!(
+            Err("-1 is not a supported depth. Use either a number or `all`.".to_owned()),
+            Depth::parse("-1")
+        );
+        assert_eq!(
+            Err("other is not a supported depth. Use either a number or `all`.".to_owned()),
+            Depth::parse("other")
+        );
+    }
+}
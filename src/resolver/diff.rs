@@ -0,0 +1,115 @@
+use crate::domain::{CrateName, CrateVersion};
+use crate::resolver::DependencyGraph;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyPackageChange {
+    pub name: CrateName,
+    /// Versions of the crate in the first graph, ordered by version and empty when it was added.
+    pub from: Vec<CrateVersion>,
+    /// Versions of the crate in the second graph, ordered by version and empty when it was removed.
+    pub to: Vec<CrateVersion>,
+}
+
+impl DependencyGraph {
+    /// Compares the crates both graphs resolved to, leaving out their root crates. Crates are
+    /// reported in the order they were resolved, those only found in the second graph last.
+    pub fn diff(&self, other: &DependencyGraph) -> Vec<DependencyPackageChange> {
+        let mut names = Vec::new();
+        let mut versions = HashMap::<&CrateName, (Vec<CrateVersion>, Vec<CrateVersion>)>::new();
+
+        for (graph, is_from) in &[(self, true), (other, false)] {
+            for node in graph.nodes.iter().skip(1) {
+                let (from, to) = versions.entry(&node.metadata.name).or_insert_with(|| {
+                    names.push(&node.metadata.name);
+                    (Vec::new(), Vec::new())
+                });
+
+                if *is_from {
+                    from.push(node.metadata.version.clone());
+                } else {
+                    to.push(node.metadata.version.clone());
+                }
+            }
+        }
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let (mut from, mut to) = versions.remove(name)?;
+                from.sort();
+                to.sort();
+
+                if from == to {
+                    return None;
+                }
+
+                Some(DependencyPackageChange {
+                    name: name.clone(),
+                    from,
+                    to,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::version;
+    use crate::resolver::test_support::{self, node};
+
+    #[test]
+    fn diff_returns_empty_when_crates_are_equal() {
+        let a = graph(&[("a", "1.0.0"), ("b", "1.0.0")]);
+        let b = graph(&[("a", "1.2.0"), ("b", "1.0.0")]);
+
+        assert!(a.diff(&b).is_empty());
+    }
+
+    #[test]
+    fn diff_returns_added_removed_and_changed_crates() {
+        let a = graph(&[
+            ("a", "1.0.0"),
+            ("b", "1.0.0"),
+            ("c", "1.0.0"),
+            ("d", "1.0.0"),
+        ]);
+        let b = graph(&[
+            ("a", "1.2.0"),
+            ("e", "1.0.0"),
+            ("c", "2.0.0"),
+            ("d", "1.0.0"),
+            ("c", "1.0.0"),
+        ]);
+
+        assert_eq!(
+            vec![
+                change("b", &["1.0.0"], &[]),
+                change("c", &["1.0.0"], &["1.0.0", "2.0.0"]),
+                change("e", &[], &["1.0.0"]),
+            ],
+            a.diff(&b)
+        );
+    }
+
+    fn graph(nodes: &[(&str, &str)]) -> DependencyGraph {
+        test_support::graph(
+            nodes
+                .iter()
+                .map(|(name, version)| node(name, version, &[]))
+                .collect(),
+        )
+    }
+
+    fn change(name: &str, from: &[&str], to: &[&str]) -> DependencyPackageChange {
+        let versions = |versions: &[&str]| versions.iter().map(|value| version(value)).collect();
+
+        DependencyPackageChange {
+            name: CrateName::parse(name).unwrap(),
+            from: versions(from),
+            to: versions(to),
+        }
+    }
+}
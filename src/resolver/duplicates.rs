@@ -0,0 +1,124 @@
+use crate::domain::CrateName;
+use crate::resolver::{DependencyEdge, DependencyGraph};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyDuplicate {
+    pub name: CrateName,
+    /// Versions of the crate in the graph, ordered by version.
+    pub versions: Vec<DependencyDuplicateVersion>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyDuplicateVersion {
+    /// Index of the version in the graph nodes.
+    pub node: usize,
+    /// Edges of the dependents that resolved to this version.
+    pub dependents: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    /// Finds the crates that appear at semver incompatible versions, which Cargo cannot unify and
+    /// therefore builds more than once. Crates are reported in the order they were resolved.
+    pub fn duplicates(&self) -> Vec<DependencyDuplicate> {
+        let mut dependents = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges() {
+            dependents[edge.to].push(edge);
+        }
+
+        let mut names = Vec::new();
+        let mut versions = HashMap::<&CrateName, Vec<usize>>::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            versions
+                .entry(&node.metadata.name)
+                .or_insert_with(|| {
+                    names.push(&node.metadata.name);
+                    Vec::new()
+                })
+                .push(index);
+        }
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let mut indices = versions.remove(name)?;
+                indices.sort_by_key(|index| &self.nodes[*index].metadata.version);
+
+                let first = &self.nodes[indices[0]].metadata.version;
+                if indices.iter().all(|index| {
+                    self.nodes[*index]
+                        .metadata
+                        .version
+                        .is_compatible_with(first)
+                }) {
+                    return None;
+                }
+
+                Some(DependencyDuplicate {
+                    name: name.clone(),
+                    versions: indices
+                        .into_iter()
+                        .map(|node| DependencyDuplicateVersion {
+                            node,
+                            dependents: std::mem::take(&mut dependents[node]),
+                        })
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::name;
+    use crate::resolver::test_support::{graph, node};
+
+    #[test]
+    fn duplicates_returns_empty_when_versions_are_compatible() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0"), ("c", "1.0.0")]),
+            node("b", "1.0.0", &[("c", "1.2.0")]),
+            node("c", "1.0.0", &[]),
+            node("c", "1.2.0", &[]),
+        ]);
+
+        assert!(graph.duplicates().is_empty());
+    }
+
+    #[test]
+    fn duplicates_returns_incompatible_versions_with_dependents() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0"), ("c", "2.0.0")]),
+            node("b", "1.0.0", &[("c", "1.0.0")]),
+            node("c", "2.0.0", &[]),
+            node("c", "1.0.0", &[]),
+        ]);
+
+        assert_eq!(
+            vec![DependencyDuplicate {
+                name: name("c"),
+                versions: vec![
+                    DependencyDuplicateVersion {
+                        node: 3,
+                        dependents: vec![DependencyEdge {
+                            from: 1,
+                            dependency: 0,
+                            to: 3
+                        }]
+                    },
+                    DependencyDuplicateVersion {
+                        node: 2,
+                        dependents: vec![DependencyEdge {
+                            from: 0,
+                            dependency: 1,
+                            to: 2
+                        }]
+                    },
+                ]
+            }],
+            graph.duplicates()
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ResolverError {
+    /// Postgres failed to read or write the cache.
+    Postgres(sqlx::Error),
+    /// crates.io served data this service cannot parse, which is not cached.
+    CratesIo(String),
+}
+
+impl fmt::Display for ResolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Postgres(error) => write!(f, "{}", error),
+            Self::CratesIo(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<sqlx::Error> for ResolverError {
+    fn from(error: sqlx::Error) -> Self {
+        Self::Postgres(error)
+    }
+}
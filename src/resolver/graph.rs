@@ -0,0 +1,254 @@
+use crate::domain::{CrateFeatures, CrateManifest, CrateMetadata, CrateName, CrateVersion};
+use crate::resolver::{ResolveOptions, Resolver, ResolverError};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+pub struct DependencyGraph {
+    pub nodes: Vec<DependencyNode>,
+}
+
+pub struct DependencyNode {
+    pub metadata: CrateMetadata,
+    pub resolved_versions: Vec<Option<CrateVersion>>,
+    /// Whether the version was yanked, unknown when crates.io does not list the version.
+    pub yanked: Option<bool>,
+    /// Checksum of the crate archive, unknown when crates.io does not list the version.
+    pub checksum: Option<String>,
+    /// SPDX license expression as crates.io lists it, unknown when crates.io does not list the
+    /// version or the crate only ships a license file.
+    pub license: Option<String>,
+    /// Features enabled on this crate, only known when features are resolved.
+    pub features: Option<BTreeSet<String>>,
+    /// Features each dependency is built with, only known when features are resolved.
+    pub dependency_features: Vec<Option<BTreeSet<String>>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DependencyEdge {
+    /// Index of the dependent in the graph nodes.
+    pub from: usize,
+    /// Index of the dependency within the dependencies of the dependent.
+    pub dependency: usize,
+    /// Index of the dependency in the graph nodes.
+    pub to: usize,
+}
+
+impl DependencyGraph {
+    /// Returns the edges whose dependency was resolved to a node of the graph, in node order.
+    pub fn edges(&self) -> Vec<DependencyEdge> {
+        let indices = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| ((&node.metadata.name, &node.metadata.version), index))
+            .collect::<HashMap<(&CrateName, &CrateVersion), usize>>();
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(from, node)| {
+                let indices = &indices;
+                node.metadata
+                    .dependencies
+                    .iter()
+                    .zip(&node.resolved_versions)
+                    .enumerate()
+                    .filter_map(move |(dependency, (crate_dependency, resolved_version))| {
+                        let to =
+                            *indices.get(&(&crate_dependency.name, resolved_version.as_ref()?))?;
+                        Some(DependencyEdge {
+                            from,
+                            dependency,
+                            to,
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+struct Entry {
+    metadata: CrateMetadata,
+    level: usize,
+    requested_features: BTreeSet<String>,
+    node: Option<DependencyNode>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Resolves the dependency graph breadth first, visiting each crate version once so cycles
+    /// through dev dependencies terminate. When features are resolved, a crate is revisited
+    /// whenever another dependent requests additional features on it, since Cargo unifies the
+    /// features of a crate across the whole graph.
+    #[tracing::instrument(
+        skip(self, name, version),
+        fields(
+            crate_name = %name.as_str(),
+            crate_version = %version.as_str(),
+        ),
+    )]
+    pub async fn resolve(
+        &mut self,
+        name: &CrateName,
+        version: &CrateVersion,
+        options: &ResolveOptions,
+    ) -> Result<Option<DependencyGraph>, ResolverError> {
+        let root = match self.crate_metadata(name, version).await? {
+            Some(metadata) => metadata,
+            None => return Ok(None),
+        };
+
+        self.resolve_from(root, None, options).await.map(Some)
+    }
+
+    /// Resolves the dependency graph of a crate that is not published, taking its features and
+    /// license from the manifest instead of crates.io.
+    #[tracing::instrument(
+        skip(self, manifest, options),
+        fields(
+            crate_name = %manifest.metadata.name.as_str(),
+            crate_version = %manifest.metadata.version.as_str(),
+        ),
+    )]
+    pub async fn resolve_manifest(
+        &mut self,
+        manifest: &CrateManifest,
+        options: &ResolveOptions,
+    ) -> Result<DependencyGraph, ResolverError> {
+        let mut graph = self
+            .resolve_from(manifest.metadata.clone(), Some(&manifest.features), options)
+            .await?;
+
+        if let Some(root) = graph.nodes.first_mut() {
+            root.license = manifest.license.clone();
+        }
+
+        Ok(graph)
+    }
+
+    /// The root crate is only looked up on crates.io when its features are not given.
+    async fn resolve_from(
+        &mut self,
+        root: CrateMetadata,
+        root_features: Option<&CrateFeatures>,
+        options: &ResolveOptions,
+    ) -> Result<DependencyGraph, ResolverError> {
+        let mut visited = HashMap::new();
+        visited.insert((root.name.clone(), root.version.clone()), Some(0));
+
+        let mut entries = vec![Entry {
+            metadata: root,
+            level: 0,
+            requested_features: options.features.clone().unwrap_or_default(),
+            node: None,
+        }];
+
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+
+        while let Some(index) = queue.pop_front() {
+            let level = entries[index].level;
+
+            let mut metadata = entries[index].metadata.clone();
+            metadata
+                .dependencies
+                .retain(|dependency| options.applies_to(dependency));
+
+            let (release, features) = match root_features.filter(|_| index == 0) {
+                Some(features) => (None, features.clone()),
+                None => {
+                    let release = self
+                        .crate_release(&metadata.name, &metadata.version)
+                        .await?;
+                    let features = release
+                        .as_ref()
+                        .map(|release| release.features.clone())
+                        .unwrap_or_default();
+                    (release, features)
+                }
+            };
+
+            let activation = options.features.as_ref().map(|_| {
+                features.activate(&metadata.dependencies, &entries[index].requested_features)
+            });
+
+            if let Some(activation) = &activation {
+                metadata
+                    .dependencies
+                    .retain(|dependency| activation.is_activated(dependency));
+            }
+
+            let mut resolved_versions = Vec::with_capacity(metadata.dependencies.len());
+            let mut dependency_features = Vec::with_capacity(metadata.dependencies.len());
+
+            for dependency in &metadata.dependencies {
+                let features = activation
+                    .as_ref()
+                    .map(|activation| activation.features_for(dependency));
+
+                if !options.depth.allows(level + 1) || !options.follows(dependency, level) {
+                    resolved_versions.push(None);
+                    dependency_features.push(features);
+                    continue;
+                }
+
+                let resolved_version = self
+                    .resolve_requirement(&dependency.name, &dependency.requirement)
+                    .await?;
+
+                if let Some(resolved_version) = &resolved_version {
+                    let key = (dependency.name.clone(), resolved_version.clone());
+
+                    match visited.get(&key) {
+                        Some(Some(child)) => {
+                            let entry = &mut entries[*child];
+
+                            if let Some(features) = &features {
+                                if !features.is_subset(&entry.requested_features) {
+                                    entry.requested_features.extend(features.iter().cloned());
+                                    queue.push_back(*child);
+                                }
+                            }
+                        }
+                        Some(None) => {}
+                        None => {
+                            let child = self
+                                .crate_metadata(&dependency.name, resolved_version)
+                                .await?
+                                .map(|metadata| {
+                                    entries.push(Entry {
+                                        metadata,
+                                        level: level + 1,
+                                        requested_features: features.clone().unwrap_or_default(),
+                                        node: None,
+                                    });
+                                    entries.len() - 1
+                                });
+
+                            if let Some(child) = child {
+                                queue.push_back(child);
+                            }
+
+                            visited.insert(key, child);
+                        }
+                    }
+                }
+
+                resolved_versions.push(resolved_version);
+                dependency_features.push(features);
+            }
+
+            entries[index].node = Some(DependencyNode {
+                metadata,
+                resolved_versions,
+                yanked: release.as_ref().map(|release| release.yanked),
+                license: release.as_ref().and_then(|release| release.license.clone()),
+                checksum: release.map(|release| release.checksum),
+                features: activation.map(|activation| activation.enabled),
+                dependency_features,
+            });
+        }
+
+        let nodes = entries.into_iter().filter_map(|entry| entry.node).collect();
+
+        Ok(DependencyGraph { nodes })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::domain::CrateLicense;
+use crate::resolver::DependencyGraph;
+use std::cmp::Ordering;
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyLicense {
+    /// License expression of the crates, normalized when it is a valid SPDX expression and as
+    /// crates.io lists it otherwise. Unknown when crates.io lists none.
+    pub expression: Option<String>,
+    /// Parsed license expression, unknown when the expression is unknown or not valid.
+    pub license: Option<CrateLicense>,
+    /// Indices of the crates in the graph nodes, in node order.
+    pub nodes: Vec<usize>,
+}
+
+impl DependencyGraph {
+    /// Groups the crates by license expression. Expressions are compared once parsed, so
+    /// `MIT/Apache-2.0` and `Apache-2.0 OR MIT` end up in the same group. Groups are ordered by
+    /// expression, with the crates of unknown license last.
+    pub fn licenses(&self) -> Vec<DependencyLicense> {
+        let mut licenses = Vec::<DependencyLicense>::new();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let license = node
+                .license
+                .as_deref()
+                .and_then(|license| CrateLicense::parse(license).ok());
+            let expression = match &license {
+                Some(license) => Some(license.to_string()),
+                None => node.license.clone(),
+            };
+
+            match licenses
+                .iter_mut()
+                .find(|other| other.expression == expression)
+            {
+                Some(other) => other.nodes.push(index),
+                None => licenses.push(DependencyLicense {
+                    expression,
+                    license,
+                    nodes: vec![index],
+                }),
+            }
+        }
+
+        licenses.sort_by(|a, b| match (&a.expression, &b.expression) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        licenses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resolver::test_support::{graph, licensed_node};
+
+    #[test]
+    fn licenses_groups_crates_by_expression() {
+        let graph = graph(vec![
+            licensed_node("a", "1.0.0", None, &[]),
+            licensed_node("b", "1.0.0", Some("MIT OR Apache-2.0"), &[]),
+            licensed_node("c", "1.0.0", Some("MIT"), &[]),
+            licensed_node("d", "1.0.0", Some("MIT/Apache-2.0"), &[]),
+            licensed_node("e", "1.0.0", Some("MIT OR"), &[]),
+            licensed_node("f", "1.0.0", Some("Apache-2.0 OR MIT"), &[]),
+        ]);
+
+        let licenses = graph.licenses();
+
+        assert_eq!(
+            vec![
+                (Some("Apache-2.0 OR MIT"), true, vec![1, 3, 5]),
+                (Some("MIT"), true, vec![2]),
+                (Some("MIT OR"), false, vec![4]),
+                (None, false, vec![0]),
+            ],
+            licenses
+                .iter()
+                .map(|license| (
+                    license.expression.as_deref(),
+                    license.license.is_some(),
+                    license.nodes.clone()
+                ))
+                .collect::<Vec<_>>()
+        );
+    }
+}
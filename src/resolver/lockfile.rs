@@ -0,0 +1,89 @@
+use crate::domain::{
+    CargoLock, CargoLockPackage, CrateDependency, CrateDependencyType, CrateMetadata,
+    CrateRequirement, CRATES_IO_SOURCE,
+};
+use crate::resolver::{DependencyGraph, DependencyNode, Resolver, ResolverError};
+
+impl<'a> Resolver<'a> {
+    /// Builds the dependency graph pinned by a lockfile, caching the metadata of each package
+    /// published on crates.io. Packages from other sources, or unknown to crates.io, only have the
+    /// dependencies recorded in the lockfile.
+    #[tracing::instrument(
+        skip(self, lockfile),
+        fields(
+            packages = lockfile.packages.len(),
+        ),
+    )]
+    pub async fn resolve_lockfile(
+        &mut self,
+        lockfile: &CargoLock,
+    ) -> Result<DependencyGraph, ResolverError> {
+        let mut nodes = Vec::with_capacity(lockfile.packages.len());
+
+        for package in &lockfile.packages {
+            let metadata = match package.source.as_deref() {
+                Some(CRATES_IO_SOURCE) => {
+                    self.crate_metadata(&package.name, &package.version).await?
+                }
+                _ => None,
+            };
+
+            let release = match metadata {
+                Some(_) => self.crate_release(&package.name, &package.version).await?,
+                None => None,
+            };
+
+            let metadata = metadata.unwrap_or_else(|| locked_metadata(package));
+
+            let resolved_versions = metadata
+                .dependencies
+                .iter()
+                .map(|dependency| {
+                    package
+                        .dependencies
+                        .iter()
+                        .find(|(name, version)| {
+                            name == &dependency.name && dependency.requirement.matches(version)
+                        })
+                        .map(|(_, version)| version.clone())
+                })
+                .collect();
+
+            nodes.push(DependencyNode {
+                dependency_features: vec![None; metadata.dependencies.len()],
+                metadata,
+                resolved_versions,
+                yanked: release.as_ref().map(|release| release.yanked),
+                license: release.as_ref().and_then(|release| release.license.clone()),
+                checksum: package
+                    .checksum
+                    .clone()
+                    .or_else(|| release.map(|release| release.checksum)),
+                features: None,
+            });
+        }
+
+        Ok(DependencyGraph { nodes })
+    }
+}
+
+/// Lockfiles do not record requirements, so each dependency requires the locked version exactly.
+fn locked_metadata(package: &CargoLockPackage) -> CrateMetadata {
+    CrateMetadata {
+        name: package.name.clone(),
+        version: package.version.clone(),
+        dependencies: package
+            .dependencies
+            .iter()
+            .map(|(name, version)| CrateDependency {
+                name: name.clone(),
+                requirement: CrateRequirement::parse(&format!("={}", version.as_str())).unwrap(),
+                type_: CrateDependencyType::Normal,
+                optional: false,
+                default_features: true,
+                features: vec![],
+                target: None,
+            })
+            .collect(),
+    }
+}
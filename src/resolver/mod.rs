@@ -0,0 +1,256 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{
+    CrateMetadata, CrateName, CrateRelease, CrateRequirement, CrateVersion, CrateVersionSelector,
+};
+use crate::postgres_client::PostgresClient;
+use std::collections::HashMap;
+
+mod cycles;
+mod depth;
+mod diff;
+mod duplicates;
+mod error;
+mod graph;
+mod licenses;
+mod lockfile;
+mod options;
+mod outdated;
+mod paths;
+mod policy;
+mod summary;
+
+pub use depth::*;
+pub use diff::*;
+pub use duplicates::*;
+pub use error::*;
+pub use graph::*;
+pub use licenses::*;
+pub use options::*;
+pub use outdated::*;
+pub use paths::*;
+pub use policy::*;
+pub use summary::*;
+
+pub struct Resolver<'a> {
+    crates_io_client: &'a CratesIoClient,
+    postgres_client: &'a PostgresClient,
+    releases: HashMap<CrateName, Option<Vec<CrateRelease>>>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(crates_io_client: &'a CratesIoClient, postgres_client: &'a PostgresClient) -> Self {
+        Self {
+            crates_io_client,
+            postgres_client,
+            releases: HashMap::new(),
+        }
+    }
+
+    #[tracing::instrument(
+        skip(self, name, version),
+        fields(
+            crate_name = %name.as_str(),
+            crate_version = %version.as_str(),
+        ),
+    )]
+    pub async fn crate_metadata(
+        &self,
+        name: &CrateName,
+        version: &CrateVersion,
+    ) -> Result<Option<CrateMetadata>, ResolverError> {
+        if let Some(metadata) = self
+            .postgres_client
+            .get_crate_metadata(name, version)
+            .await?
+        {
+            return Ok(Some(metadata));
+        }
+
+        let metadata = match self
+            .crates_io_client
+            .dependencies(name, version)
+            .await
+            .map_err(ResolverError::CratesIo)?
+        {
+            Some(metadata) => metadata,
+            None => return Ok(None),
+        };
+
+        self.postgres_client.save_crate_metadata(&metadata).await?;
+
+        Ok(Some(metadata))
+    }
+
+    #[tracing::instrument(
+        skip(self, name),
+        fields(
+            crate_name = %name.as_str(),
+        ),
+    )]
+    pub async fn crate_releases(
+        &self,
+        name: &CrateName,
+    ) -> Result<Option<Vec<CrateRelease>>, ResolverError> {
+        if let Some(releases) = self.postgres_client.get_crate_releases(name).await? {
+            return Ok(Some(releases));
+        }
+
+        let releases = match self.crates_io_client.versions(name).await {
+            Some(releases) => releases,
+            None => return Ok(None),
+        };
+
+        self.postgres_client
+            .save_crate_releases(name, &releases)
+            .await?;
+
+        Ok(Some(releases))
+    }
+
+    /// Returns the release of the given version, when crates.io knows about it.
+    #[tracing::instrument(
+        skip(self, name, version),
+        fields(
+            crate_name = %name.as_str(),
+            crate_version = %version.as_str(),
+        ),
+    )]
+    pub async fn crate_release(
+        &mut self,
+        name: &CrateName,
+        version: &CrateVersion,
+    ) -> Result<Option<CrateRelease>, ResolverError> {
+        let release = self
+            .releases(name)
+            .await?
+            .and_then(|releases| releases.iter().find(|release| &release.version == version))
+            .cloned();
+
+        Ok(release)
+    }
+
+    /// Yanked versions are skipped, as Cargo does for requirements that are not locked.
+    #[tracing::instrument(
+        skip(self, name, requirement),
+        fields(
+            crate_name = %name.as_str(),
+            crate_requirement = %requirement.as_str(),
+        ),
+    )]
+    pub async fn resolve_requirement(
+        &mut self,
+        name: &CrateName,
+        requirement: &CrateRequirement,
+    ) -> Result<Option<CrateVersion>, ResolverError> {
+        let version = self.releases(name).await?.and_then(|releases| {
+            requirement
+                .best_match(
+                    releases
+                        .iter()
+                        .filter(|release| !release.yanked)
+                        .map(|release| &release.version),
+                )
+                .cloned()
+        });
+
+        Ok(version)
+    }
+
+    /// Exact versions are returned as they are, so unknown versions are only reported once their
+    /// metadata is requested.
+    #[tracing::instrument(
+        skip(self, name, selector),
+        fields(
+            crate_name = %name.as_str(),
+            crate_version_selector = ?selector,
+        ),
+    )]
+    pub async fn select_version(
+        &mut self,
+        name: &CrateName,
+        selector: &CrateVersionSelector,
+    ) -> Result<Option<CrateVersion>, ResolverError> {
+        if let CrateVersionSelector::Exact(version) = selector {
+            return Ok(Some(version.clone()));
+        }
+
+        let version = self
+            .releases(name)
+            .await?
+            .and_then(|releases| selector.select(releases).cloned());
+
+        Ok(version)
+    }
+
+    async fn releases(
+        &mut self,
+        name: &CrateName,
+    ) -> Result<Option<&[CrateRelease]>, ResolverError> {
+        if !self.releases.contains_key(name) {
+            let releases = self.crate_releases(name).await?;
+            self.releases.insert(name.clone(), releases);
+        }
+
+        Ok(self
+            .releases
+            .get(name)
+            .and_then(|releases| releases.as_deref()))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::domain::test_support::{dependency, name, version};
+    use crate::domain::{CrateDependencyType, CrateMetadata};
+    use crate::resolver::{DependencyGraph, DependencyNode};
+
+    pub fn graph(nodes: Vec<DependencyNode>) -> DependencyGraph {
+        DependencyGraph { nodes }
+    }
+
+    /// Node whose normal dependencies each require, and resolved to, exactly the given version.
+    pub fn node(
+        name_value: &str,
+        version_value: &str,
+        dependencies: &[(&str, &str)],
+    ) -> DependencyNode {
+        DependencyNode {
+            metadata: CrateMetadata {
+                name: name(name_value),
+                version: version(version_value),
+                dependencies: dependencies
+                    .iter()
+                    .map(|(name_value, version_value)| {
+                        dependency(
+                            name_value,
+                            &format!("={}", version_value),
+                            CrateDependencyType::Normal,
+                        )
+                    })
+                    .collect(),
+            },
+            resolved_versions: dependencies
+                .iter()
+                .map(|(_, version_value)| Some(version(version_value)))
+                .collect(),
+            yanked: None,
+            checksum: None,
+            license: None,
+            features: None,
+            dependency_features: vec![None; dependencies.len()],
+        }
+    }
+
+    /// Same as [`node`], with the license crates.io lists for the version.
+    pub fn licensed_node(
+        name_value: &str,
+        version_value: &str,
+        license: Option<&str>,
+        dependencies: &[(&str, &str)],
+    ) -> DependencyNode {
+        DependencyNode {
+            license: license.map(|license| license.to_owned()),
+            ..node(name_value, version_value, dependencies)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::{CrateDependency, CrateDependencyType, TargetPlatform};
+use crate::resolver::Depth;
+use std::collections::BTreeSet;
+
+#[derive(Debug)]
+pub struct ResolveOptions {
+    pub depth: Depth,
+    pub platform: Option<TargetPlatform>,
+    /// Features requested on the root crate, including `default` unless default features are
+    /// disabled. Optional dependencies are only followed once activated when features are given.
+    pub features: Option<BTreeSet<String>>,
+    /// Whether the dev dependencies of the root crate are followed.
+    pub dev_dependencies: bool,
+}
+
+impl ResolveOptions {
+    /// Dependencies declared for another platform are dropped when a platform is given.
+    pub fn applies_to(&self, dependency: &CrateDependency) -> bool {
+        match (&self.platform, &dependency.target) {
+            (Some(platform), Some(target)) => target.applies_to(platform),
+            _ => true,
+        }
+    }
+
+    /// Dev dependencies are only built for the root crate, so they are not followed any deeper.
+    pub fn follows(&self, dependency: &CrateDependency, level: usize) -> bool {
+        dependency.type_ != CrateDependencyType::Dev || (self.dev_dependencies && level == 0)
+    }
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        Self {
+            depth: Depth::Limited(0),
+            platform: None,
+            features: None,
+            dev_dependencies: true,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::domain::CrateOutdated;
+use crate::resolver::{DependencyGraph, Resolver, ResolverError};
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyOutdated {
+    /// Index of the dependent in the graph nodes.
+    pub node: usize,
+    /// Index of the dependency within the dependencies of the dependent.
+    pub dependency: usize,
+    pub outdated: CrateOutdated,
+}
+
+impl<'a> Resolver<'a> {
+    /// Compares the requirement of every dependency of the root crate against its published
+    /// versions, and of every crate in the graph when `transitive`. Dependencies the graph did
+    /// not follow are compared too, in node order.
+    #[tracing::instrument(skip(self, graph))]
+    pub async fn outdated(
+        &mut self,
+        graph: &DependencyGraph,
+        transitive: bool,
+    ) -> Result<Vec<DependencyOutdated>, ResolverError> {
+        let nodes = if transitive { graph.nodes.len() } else { 1 };
+        let mut outdated = Vec::new();
+
+        for (index, node) in graph.nodes.iter().take(nodes).enumerate() {
+            for (dependency, crate_dependency) in node.metadata.dependencies.iter().enumerate() {
+                let releases = self.releases(&crate_dependency.name).await?;
+
+                outdated.push(DependencyOutdated {
+                    node: index,
+                    dependency,
+                    outdated: CrateOutdated::new(
+                        &crate_dependency.requirement,
+                        releases.unwrap_or_default(),
+                    ),
+                });
+            }
+        }
+
+        Ok(outdated)
+    }
+}
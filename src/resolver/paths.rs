@@ -0,0 +1,220 @@
+use crate::domain::CrateName;
+use crate::resolver::{DependencyEdge, DependencyGraph};
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyPath {
+    /// Edges in the order they are followed from the root crate.
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    /// Finds the paths from the root crate to every version of the named crate, at most `limit`
+    /// of them as their number grows exponentially with the size of the graph. Crates that cannot
+    /// reach the named crate are pruned first, and no crate is visited twice on the same path.
+    pub fn paths(&self, name: &CrateName, limit: usize) -> Vec<DependencyPath> {
+        let edges = self.edges();
+
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        let mut dependents = vec![Vec::new(); self.nodes.len()];
+        for edge in &edges {
+            adjacency[edge.from].push(*edge);
+            dependents[edge.to].push(edge.from);
+        }
+
+        let mut reaches = vec![false; self.nodes.len()];
+        let mut queue = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if &node.metadata.name == name {
+                reaches[index] = true;
+                queue.push(index);
+            }
+        }
+        while let Some(index) = queue.pop() {
+            for &dependent in &dependents[index] {
+                if !reaches[dependent] {
+                    reaches[dependent] = true;
+                    queue.push(dependent);
+                }
+            }
+        }
+
+        let mut paths = Vec::new();
+        if self.nodes.is_empty() || !reaches[0] || &self.nodes[0].metadata.name == name {
+            return paths;
+        }
+
+        let mut on_path = vec![false; self.nodes.len()];
+        on_path[0] = true;
+        let mut stack = vec![(0, 0)];
+        let mut path: Vec<DependencyEdge> = Vec::new();
+
+        while let Some(&(node, next)) = stack.last() {
+            if paths.len() >= limit {
+                break;
+            }
+
+            let edge = match adjacency[node].get(next) {
+                Some(edge) => *edge,
+                None => {
+                    on_path[node] = false;
+                    stack.pop();
+                    path.pop();
+                    continue;
+                }
+            };
+
+            let top = stack.len() - 1;
+            stack[top].1 += 1;
+
+            if !reaches[edge.to] || on_path[edge.to] {
+                continue;
+            }
+
+            if &self.nodes[edge.to].metadata.name == name {
+                let mut edges = path.clone();
+                edges.push(edge);
+                paths.push(DependencyPath { edges });
+                continue;
+            }
+
+            on_path[edge.to] = true;
+            stack.push((edge.to, 0));
+            path.push(edge);
+        }
+
+        paths
+    }
+
+    /// Finds one of the shortest paths from any of the `roots` to each crate, searching breadth
+    /// first. Paths are in node order, unknown for the crates no root reaches.
+    pub fn shortest_paths(&self, roots: &[usize]) -> Vec<Option<DependencyPath>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges() {
+            adjacency[edge.from].push(edge);
+        }
+
+        let mut paths = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        for &root in roots {
+            if paths[root].is_none() {
+                paths[root] = Some(DependencyPath { edges: Vec::new() });
+                queue.push_back(root);
+            }
+        }
+
+        while let Some(index) = queue.pop_front() {
+            for edge in &adjacency[index] {
+                if paths[edge.to].is_none() {
+                    let mut edges = paths[index]
+                        .as_ref()
+                        .map(|path: &DependencyPath| path.edges.clone())
+                        .unwrap_or_default();
+                    edges.push(*edge);
+                    paths[edge.to] = Some(DependencyPath { edges });
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::name;
+    use crate::resolver::test_support::{graph, node};
+
+    #[test]
+    fn paths_returns_empty_when_crate_is_not_a_dependency() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0")]),
+            node("b", "1.0.0", &[]),
+            node("c", "1.0.0", &[]),
+        ]);
+
+        assert!(graph.paths(&name("c"), 10).is_empty());
+        assert!(graph.paths(&name("a"), 10).is_empty());
+    }
+
+    #[test]
+    fn paths_returns_every_path_to_crate() {
+        let graph = graph(vec![
+            node(
+                "a",
+                "1.0.0",
+                &[("b", "1.0.0"), ("c", "1.0.0"), ("d", "1.0.0")],
+            ),
+            node("b", "1.0.0", &[("d", "1.0.0")]),
+            node("c", "1.0.0", &[("b", "1.0.0"), ("e", "1.0.0")]),
+            node("d", "1.0.0", &[("b", "1.0.0")]),
+            node("e", "1.0.0", &[]),
+        ]);
+
+        assert_eq!(
+            vec![
+                path(&[(0, 0, 1), (1, 0, 3)]),
+                path(&[(0, 1, 2), (2, 0, 1), (1, 0, 3)]),
+                path(&[(0, 2, 3)]),
+            ],
+            graph.paths(&name("d"), 10)
+        );
+    }
+
+    #[test]
+    fn paths_stops_at_limit() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0"), ("c", "1.0.0")]),
+            node("b", "1.0.0", &[("c", "1.0.0")]),
+            node("c", "1.0.0", &[]),
+        ]);
+
+        assert_eq!(
+            vec![path(&[(0, 0, 1), (1, 0, 2)])],
+            graph.paths(&name("c"), 1)
+        );
+    }
+
+    #[test]
+    fn shortest_paths_returns_fewest_edges_from_any_root() {
+        let graph = graph(vec![
+            node("a", "1.0.0", &[("b", "1.0.0"), ("c", "1.0.0")]),
+            node("b", "1.0.0", &[("d", "1.0.0")]),
+            node("c", "1.0.0", &[("b", "1.0.0"), ("e", "1.0.0")]),
+            node("d", "1.0.0", &[]),
+            node("e", "1.0.0", &[("d", "1.0.0")]),
+            node("f", "1.0.0", &[]),
+        ]);
+
+        assert_eq!(
+            vec![
+                Some(path(&[])),
+                Some(path(&[(0, 0, 1)])),
+                Some(path(&[(0, 1, 2)])),
+                Some(path(&[(0, 0, 1), (1, 0, 3)])),
+                Some(path(&[(0, 1, 2), (2, 1, 4)])),
+                None,
+            ],
+            graph.shortest_paths(&[0])
+        );
+        assert_eq!(
+            Some(path(&[(2, 1, 4)])),
+            graph.shortest_paths(&[0, 2]).remove(4)
+        );
+    }
+
+    fn path(edges: &[(usize, usize, usize)]) -> DependencyPath {
+        DependencyPath {
+            edges: edges
+                .iter()
+                .map(|&(from, dependency, to)| DependencyEdge {
+                    from,
+                    dependency,
+                    to,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::domain::{CrateLicense, CratePolicy};
+use crate::resolver::{DependencyGraph, DependencyPath};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PolicyRule {
+    License,
+    Ban,
+    Duplicate,
+    Wildcard,
+}
+
+impl PolicyRule {
+    pub fn as_str(&self) -> &str {
+        match &self {
+            PolicyRule::License => "license",
+            PolicyRule::Ban => "ban",
+            PolicyRule::Duplicate => "duplicate",
+            PolicyRule::Wildcard => "wildcard",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PolicyViolation {
+    pub rule: PolicyRule,
+    /// Index of the offending crate in the graph nodes.
+    pub node: usize,
+    /// Index of the offending dependency within the dependencies of the crate, only known for
+    /// wildcard requirements.
+    pub dependency: Option<usize>,
+    /// Shortest path from a root crate to the offending crate, unknown when no root reaches it.
+    pub path: Option<DependencyPath>,
+}
+
+impl DependencyGraph {
+    /// Checks every crate against the policy, reporting violations in node order. Paths lead
+    /// from the closest of the `roots`, which are the crates the graph was resolved for.
+    pub fn check(&self, policy: &CratePolicy, roots: &[usize]) -> Vec<PolicyViolation> {
+        let duplicates = if policy.deny_duplicates {
+            self.duplicates()
+                .iter()
+                .flat_map(|duplicate| duplicate.versions.iter().map(|version| version.node))
+                .collect::<HashSet<_>>()
+        } else {
+            HashSet::new()
+        };
+
+        let paths = self.shortest_paths(roots);
+        let mut violations = Vec::new();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut rules = Vec::new();
+
+            if policy.checks_licenses() {
+                let allowed = node
+                    .license
+                    .as_deref()
+                    .and_then(|license| CrateLicense::parse(license).ok())
+                    .map(|license| policy.allows_license(&license))
+                    .unwrap_or(false);
+
+                if !allowed {
+                    rules.push((PolicyRule::License, None));
+                }
+            }
+
+            if policy
+                .ban(&node.metadata.name, &node.metadata.version)
+                .is_some()
+            {
+                rules.push((PolicyRule::Ban, None));
+            }
+
+            if duplicates.contains(&index) {
+                rules.push((PolicyRule::Duplicate, None));
+            }
+
+            if policy.deny_wildcards {
+                for (dependency, crate_dependency) in node.metadata.dependencies.iter().enumerate()
+                {
+                    if crate_dependency.requirement.is_wildcard() {
+                        rules.push((PolicyRule::Wildcard, Some(dependency)));
+                    }
+                }
+            }
+
+            for (rule, dependency) in rules {
+                violations.push(PolicyViolation {
+                    rule,
+                    node: index,
+                    dependency,
+                    path: paths[index].clone(),
+                });
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_support::requirement;
+    use crate::resolver::test_support::{graph, licensed_node};
+    use crate::resolver::DependencyEdge;
+
+    #[test]
+    fn check_returns_violations_with_paths() {
+        let mut a = licensed_node("a", "1.0.0", Some("MIT"), &[("b", "1.0.0"), ("c", "1.0.0")]);
+        a.metadata.dependencies[1].requirement = requirement("*");
+        let graph = graph(vec![
+            a,
+            licensed_node("b", "1.0.0", Some("MIT OR GPL-3.0"), &[("c", "2.0.0")]),
+            licensed_node("c", "1.0.0", Some("GPL-3.0"), &[]),
+            licensed_node("c", "2.0.0", Some("MIT"), &[]),
+            licensed_node("d", "1.0.0", Some("MIT"), &[]),
+        ]);
+        let policy = CratePolicy::parse(
+            r#"
+[licenses]
+allow = ["MIT"]
+
+[bans]
+deny = [{ name = "c", version = "^2" }, { name = "d" }]
+multiple-versions = "deny"
+wildcards = "deny"
+"#,
+        )
+        .unwrap();
+
+        let violations = graph.check(&policy, &[0]);
+
+        let edge = |from, dependency, to| DependencyEdge {
+            from,
+            dependency,
+            to,
+        };
+        assert_eq!(
+            vec![
+                (PolicyRule::Wildcard, 0, Some(1), Some(vec![])),
+                (PolicyRule::License, 2, None, Some(vec![edge(0, 1, 2)])),
+                (PolicyRule::Duplicate, 2, None, Some(vec![edge(0, 1, 2)])),
+                (
+                    PolicyRule::Ban,
+                    3,
+                    None,
+                    Some(vec![edge(0, 0, 1), edge(1, 0, 3)])
+                ),
+                (
+                    PolicyRule::Duplicate,
+                    3,
+                    None,
+                    Some(vec![edge(0, 0, 1), edge(1, 0, 3)])
+                ),
+                (PolicyRule::Ban, 4, None, None),
+            ],
+            violations
+                .into_iter()
+                .map(|violation| (
+                    violation.rule,
+                    violation.node,
+                    violation.dependency,
+                    violation.path.map(|path| path.edges)
+                ))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn check_returns_nothing_for_default_policy() {
+        let mut a = licensed_node("a", "1.0.0", Some("GPL-3.0"), &[("b", "1.0.0")]);
+        a.metadata.dependencies[0].requirement = requirement("*");
+        let graph = graph(vec![a]);
+
+        assert!(graph.check(&CratePolicy::default(), &[0]).is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::domain::CrateDependencyType;
+use crate::resolver::{DependencyEdge, DependencyGraph};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, PartialEq)]
+pub struct DependencySummary {
+    /// Number of crate names in the graph.
+    pub crates: usize,
+    /// Number of crate versions in the graph.
+    pub packages: usize,
+    /// Highest number of edges between the root crate and any other crate, following the
+    /// shortest path to each crate.
+    pub max_depth: usize,
+    /// Edges of the longest path from the root crate, leaving out the edges that close a cycle.
+    pub longest_chain: Vec<DependencyEdge>,
+    /// Number of resolved edges of each dependency type, in the order the types first appear.
+    pub edges: Vec<(CrateDependencyType, usize)>,
+    /// Metrics of each crate, in the order of the graph nodes.
+    pub nodes: Vec<DependencyNodeSummary>,
+    /// Direct dependencies of the root crate, in the order they were resolved.
+    pub direct_dependencies: Vec<DependencyWeight>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyNodeSummary {
+    pub depth: usize,
+    /// Number of resolved edges leading to the crate.
+    pub fan_in: usize,
+    /// Number of resolved edges leaving the crate.
+    pub fan_out: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyWeight {
+    /// Index of the dependency in the graph nodes.
+    pub node: usize,
+    /// Number of crates that leave the graph when the root crate stops depending on it,
+    /// including the dependency itself.
+    pub weight: usize,
+}
+
+impl DependencyGraph {
+    pub fn summary(&self) -> DependencySummary {
+        let edges = self.edges();
+
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        let mut nodes = self
+            .nodes
+            .iter()
+            .map(|_| DependencyNodeSummary {
+                depth: 0,
+                fan_in: 0,
+                fan_out: 0,
+            })
+            .collect::<Vec<_>>();
+        let mut edges_by_type = Vec::<(CrateDependencyType, usize)>::new();
+
+        for edge in &edges {
+            adjacency[edge.from].push(*edge);
+            nodes[edge.from].fan_out += 1;
+            nodes[edge.to].fan_in += 1;
+
+            let type_ = &self.nodes[edge.from].metadata.dependencies[edge.dependency].type_;
+            match edges_by_type.iter_mut().find(|(other, _)| other == type_) {
+                Some((_, count)) => *count += 1,
+                None => edges_by_type.push((type_.clone(), 1)),
+            }
+        }
+
+        let depths = self.depths(&adjacency, None);
+        for (node, depth) in nodes.iter_mut().zip(&depths) {
+            node.depth = depth.unwrap_or_default();
+        }
+
+        let reachable = depths.iter().flatten().count();
+
+        let mut direct_dependencies = Vec::<DependencyWeight>::new();
+        for edge in adjacency.first().into_iter().flatten() {
+            if direct_dependencies
+                .iter()
+                .any(|other| other.node == edge.to)
+            {
+                continue;
+            }
+
+            let remaining = self
+                .depths(&adjacency, Some(edge.to))
+                .iter()
+                .flatten()
+                .count();
+
+            direct_dependencies.push(DependencyWeight {
+                node: edge.to,
+                weight: reachable - remaining,
+            });
+        }
+
+        DependencySummary {
+            crates: self
+                .nodes
+                .iter()
+                .map(|node| &node.metadata.name)
+                .collect::<HashSet<_>>()
+                .len(),
+            packages: self.nodes.len(),
+            max_depth: depths.iter().flatten().copied().max().unwrap_or_default(),
+            longest_chain: self.longest_chain(&adjacency),
+            edges: edges_by_type,
+            nodes,
+            direct_dependencies,
+        }
+    }
+
+    /// Returns the length of the shortest path from the root crate to each crate, leaving out the
+    /// edges from the root crate to `excluded`.
+    fn depths(
+        &self,
+        adjacency: &[Vec<DependencyEdge>],
+        excluded: Option<usize>,
+    ) -> Vec<Option<usize>> {
+        let mut depths = vec![None; self.nodes.len()];
+        if self.nodes.is_empty() {
+            return depths;
+        }
+
+        depths[0] = Some(0);
+        let mut queue = VecDeque::from(vec![0]);
+
+        while let Some(node) = queue.pop_front() {
+            let depth = depths[node].unwrap_or_default();
+            for edge in &adjacency[node] {
+                if edge.from == 0 && Some(edge.to) == excluded {
+                    continue;
+                }
+
+                if depths[edge.to].is_none() {
+                    depths[edge.to] = Some(depth + 1);
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        depths
+    }
+
+    /// Finds the longest path with a depth first search from the root crate. Once a crate is
+    /// done, every edge that does not lead back to a crate still being visited leads to a crate
+    /// whose longest path is known.
+    fn longest_chain(&self, adjacency: &[Vec<DependencyEdge>]) -> Vec<DependencyEdge> {
+        if self.nodes.is_empty() {
+            return Vec::new();
+        }
+
+        let mut done = vec![false; self.nodes.len()];
+        let mut visited = vec![false; self.nodes.len()];
+        let mut lengths = vec![0; self.nodes.len()];
+        let mut next_edges = vec![None; self.nodes.len()];
+
+        visited[0] = true;
+        let mut stack = vec![(0, 0)];
+
+        while let Some(&(node, next)) = stack.last() {
+            if let Some(edge) = adjacency[node].get(next) {
+                let top = stack.len() - 1;
+                stack[top].1 += 1;
+
+                if !visited[edge.to] {
+                    visited[edge.to] = true;
+                    stack.push((edge.to, 0));
+                }
+                continue;
+            }
+
+            for edge in &adjacency[node] {
+                if done[edge.to] && lengths[edge.to] + 1 > lengths[node] {
+                    lengths[node] = lengths[edge.to] + 1;
+                    next_edges[node] = Some(*edge);
+                }
+            }
+
+            done[node] = true;
+            stack.pop();
+        }
+
+        let mut chain = Vec::new();
+        let mut node = 0;
+        while let Some(edge) = next_edges[node] {
+            chain.push(edge);
+            node = edge.to;
+        }
+
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolver::test_support::{graph, node};
+
+    #[test]
+    fn summary_returns_metrics() {
+        let mut a = node("a", "1.0.0", &[("b", "1.0.0"), ("c", "1.0.0")]);
+        a.metadata.dependencies[1].type_ = CrateDependencyType::Dev;
+        let mut b = node("b", "1.0.0", &[("d", "1.0.0"), ("e", "1.0.0")]);
+        b.metadata.dependencies[1].type_ = CrateDependencyType::Build;
+        let graph = graph(vec![
+            a,
+            b,
+            node("c", "1.0.0", &[("d", "2.0.0")]),
+            node("d", "1.0.0", &[("e", "1.0.0")]),
+            node("e", "1.0.0", &[]),
+            node("d", "2.0.0", &[("a", "1.0.0")]),
+        ]);
+
+        let summary = graph.summary();
+
+        assert_eq!(5, summary.crates);
+        assert_eq!(6, summary.packages);
+        assert_eq!(2, summary.max_depth);
+        assert_eq!(
+            vec![
+                DependencyEdge {
+                    from: 0,
+                    dependency: 0,
+                    to: 1
+                },
+                DependencyEdge {
+                    from: 1,
+                    dependency: 0,
+                    to: 3
+                },
+                DependencyEdge {
+                    from: 3,
+                    dependency: 0,
+                    to: 4
+                },
+            ],
+            summary.longest_chain
+        );
+        assert_eq!(
+            vec![
+                (CrateDependencyType::Normal, 5),
+                (CrateDependencyType::Dev, 1),
+                (CrateDependencyType::Build, 1),
+            ],
+            summary.edges
+        );
+        assert_eq!(
+            vec![
+                (0, 1, 2),
+                (1, 1, 2),
+                (1, 1, 1),
+                (2, 1, 1),
+                (2, 2, 0),
+                (2, 1, 1)
+            ],
+            summary
+                .nodes
+                .iter()
+                .map(|node| (node.depth, node.fan_in, node.fan_out))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                DependencyWeight { node: 1, weight: 3 },
+                DependencyWeight { node: 2, weight: 2 },
+            ],
+            summary.direct_dependencies
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::advisory_database::AdvisoryDatabase;
+use crate::configuration::AdvisoriesConfiguration;
+use crate::postgres_client::PostgresClient;
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct AdvisoriesReloadResponse {
+    #[serde(rename = "advisories")]
+    pub advisories: usize,
+}
+
+/// Scans the advisory database again, replacing the stored advisories with the ones on disk.
+///
+/// Only callers presenting the configured reload token as a bearer token are allowed.
+#[tracing::instrument(skip(request, advisories_configuration, advisory_database, postgres_client))]
+pub async fn advisories_reload(
+    request: HttpRequest,
+    advisories_configuration: web::Data<AdvisoriesConfiguration>,
+    advisory_database: web::Data<Option<AdvisoryDatabase>>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if !advisories_configuration.authorizes_reload(token) {
+        return Err(HttpResponse::Unauthorized().finish());
+    }
+
+    let advisory_database = advisory_database
+        .get_ref()
+        .as_ref()
+        .ok_or_else(|| HttpResponse::ServiceUnavailable().finish())?;
+
+    let advisories = advisory_database
+        .scan()
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    postgres_client
+        .save_crate_advisories(&advisories)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    Ok(HttpResponse::Ok().json(&AdvisoriesReloadResponse {
+        advisories: advisories.len(),
+    }))
+}
@@ -0,0 +1,141 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CrateDependent, CrateDependentSource, CrateName, CrateVersionSelector};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::Resolver;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    #[serde(rename = "name")]
+    pub crate_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReverseDependenciesQuery {
+    #[serde(rename = "version")]
+    pub crate_version: Option<String>,
+    #[serde(rename = "source")]
+    pub source: Option<String>,
+    #[serde(rename = "page")]
+    pub page: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct ReverseDependenciesResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<Dependent>,
+    #[serde(rename = "source")]
+    pub source: String,
+}
+
+#[derive(Serialize)]
+pub struct Dependent {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+    #[serde(rename = "optional")]
+    pub optional: bool,
+    #[serde(rename = "target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(rename = "matches", skip_serializing_if = "Option::is_none")]
+    pub matches: Option<bool>,
+}
+
+impl From<&CrateDependent> for Dependent {
+    fn from(dependent: &CrateDependent) -> Self {
+        Dependent {
+            name: dependent.name.as_str().to_owned(),
+            version: dependent.version.as_str().to_owned(),
+            relationship: format!("dependency.{}", dependent.dependency.type_.as_str()),
+            requirement: dependent.dependency.requirement.as_str().to_owned(),
+            optional: dependent.dependency.optional,
+            target: dependent
+                .dependency
+                .target
+                .as_ref()
+                .map(|target| target.to_string()),
+            matches: None,
+        }
+    }
+}
+
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, path, query),
+    fields(
+        crate_name = %path.crate_name,
+        crate_version = ?query.crate_version,
+        source = ?query.source,
+        page = ?query.page,
+    ),
+)]
+pub async fn crate_reverse_dependencies_query(
+    path: web::Path<Path>,
+    query: web::Query<ReverseDependenciesQuery>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let name =
+        CrateName::parse(&path.crate_name).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let source = match &query.source {
+        Some(source) => CrateDependentSource::try_from(source.as_str())
+            .map_err(|e| HttpResponse::BadRequest().body(e))?,
+        None => CrateDependentSource::CratesIo,
+    };
+
+    let selector = query
+        .crate_version
+        .as_deref()
+        .map(CrateVersionSelector::parse)
+        .transpose()
+        .map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let candidates = match selector {
+        Some(CrateVersionSelector::Exact(version)) => Some(vec![version]),
+        Some(selector) => {
+            let releases = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref())
+                .crate_releases(&name)
+                .await
+                .map_err(|_| HttpResponse::InternalServerError().finish())?
+                .ok_or_else(|| HttpResponse::NotFound().finish())?;
+
+            Some(selector.candidates(&releases))
+        }
+        None => None,
+    };
+
+    let dependents = match source {
+        CrateDependentSource::Cache => postgres_client
+            .get_crate_dependents(&name)
+            .await
+            .map_err(|_| HttpResponse::InternalServerError().finish())?,
+        CrateDependentSource::CratesIo => crates_io_client
+            .reverse_dependencies(&name, query.page.unwrap_or(1).max(1))
+            .await
+            .ok_or_else(|| HttpResponse::NotFound().finish())?,
+    };
+
+    let json = ReverseDependenciesResponse {
+        data: dependents
+            .iter()
+            .map(|dependent| Dependent {
+                matches: candidates.as_ref().map(|candidates| {
+                    candidates
+                        .iter()
+                        .any(|version| dependent.dependency.requirement.matches(version))
+                }),
+                ..Dependent::from(dependent)
+            })
+            .collect(),
+        source: source.as_str().to_owned(),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
@@ -0,0 +1,76 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CrateName, CrateRelease};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::Resolver;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    #[serde(rename = "name")]
+    pub crate_name: String,
+}
+
+#[derive(Serialize)]
+pub struct VersionsResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<Version>,
+}
+
+#[derive(Serialize)]
+pub struct Version {
+    #[serde(rename = "version")]
+    pub version: String,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "yanked")]
+    pub yanked: bool,
+    #[serde(rename = "checksum")]
+    pub checksum: String,
+    #[serde(rename = "rust_version")]
+    pub rust_version: Option<String>,
+    #[serde(rename = "license")]
+    pub license: Option<String>,
+}
+
+impl From<&CrateRelease> for Version {
+    fn from(release: &CrateRelease) -> Self {
+        Version {
+            version: release.version.as_str().to_owned(),
+            created_at: release.created_at.to_rfc3339(),
+            yanked: release.yanked,
+            checksum: release.checksum.clone(),
+            rust_version: release.rust_version.clone(),
+            license: release.license.clone(),
+        }
+    }
+}
+
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, path),
+    fields(
+        crate_name = %path.crate_name,
+    ),
+)]
+pub async fn crate_versions_query(
+    path: web::Path<Path>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let name =
+        CrateName::parse(&path.crate_name).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let mut releases = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref())
+        .crate_releases(&name)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?
+        .ok_or_else(|| HttpResponse::NotFound().finish())?;
+
+    releases.sort_by(|a, b| a.version.cmp(&b.version));
+
+    let json = VersionsResponse {
+        data: releases.iter().map(Version::from).collect(),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
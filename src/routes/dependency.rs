@@ -1,8 +1,14 @@
 use crate::crates_io_client::CratesIoClient;
-use crate::domain::{CrateName, CrateVersion};
+use crate::domain::{CrateAdvisory, CrateName, CrateVersionSelector, TargetPlatform};
 use crate::postgres_client::PostgresClient;
+use crate::resolver::{
+    DependencyEdge, DependencyGraph, DependencyLicense, DependencyNode, DependencySummary, Depth,
+    ResolveOptions, Resolver,
+};
+use crate::routes::DuplicateNode;
 use actix_web::{web, HttpResponse};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug, Deserialize)]
 pub struct Query {
@@ -10,12 +16,36 @@ pub struct Query {
     pub crate_name: String,
     #[serde(rename = "version")]
     pub crate_version: String,
+    #[serde(rename = "depth")]
+    pub depth: Option<String>,
+    #[serde(rename = "target")]
+    pub target: Option<String>,
+    #[serde(rename = "features")]
+    pub features: Option<String>,
+    #[serde(rename = "no_default_features")]
+    pub no_default_features: Option<bool>,
+    #[serde(rename = "dev_dependencies")]
+    pub dev_dependencies: Option<bool>,
+    #[serde(rename = "cycles")]
+    pub cycles: Option<bool>,
+    #[serde(rename = "summary")]
+    pub summary: Option<bool>,
+    #[serde(rename = "licenses")]
+    pub licenses: Option<bool>,
 }
 
 #[derive(Serialize)]
 pub struct Response {
     #[serde(rename = "data")]
     pub data: Vec<Node>,
+    #[serde(rename = "cycles", skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<Vec<Vec<CycleEdge>>>,
+    #[serde(rename = "duplicates", skip_serializing_if = "Option::is_none")]
+    pub duplicates: Option<Vec<DuplicateNode>>,
+    #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Summary>,
+    #[serde(rename = "licenses", skip_serializing_if = "Option::is_none")]
+    pub licenses: Option<Vec<LicenseGroup>>,
 }
 
 #[derive(Serialize)]
@@ -24,14 +54,48 @@ pub struct Node {
     pub name: String,
     #[serde(rename = "version")]
     pub version: String,
+    #[serde(rename = "yanked")]
+    pub yanked: Option<bool>,
+    #[serde(rename = "license")]
+    pub license: Option<String>,
+    #[serde(rename = "latest_version", skip_serializing_if = "Option::is_none")]
+    pub latest_version: Option<String>,
+    #[serde(rename = "features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    #[serde(rename = "advisories", skip_serializing_if = "Option::is_none")]
+    pub advisories: Option<Vec<NodeAdvisory>>,
     #[serde(rename = "edges")]
     pub edges: Vec<Edge>,
 }
 
+#[derive(Serialize)]
+pub struct NodeAdvisory {
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "severity")]
+    pub severity: Option<String>,
+    #[serde(rename = "informational", skip_serializing_if = "Option::is_none")]
+    pub informational: Option<String>,
+    #[serde(rename = "patched")]
+    pub patched: Vec<String>,
+}
+
 #[derive(Serialize)]
 pub struct Edge {
     #[serde(rename = "relationship")]
     pub relationship: String,
+    #[serde(rename = "optional")]
+    pub optional: bool,
+    #[serde(rename = "default_features")]
+    pub default_features: bool,
+    #[serde(rename = "features")]
+    pub features: Vec<String>,
+    #[serde(rename = "target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(rename = "platforms", skip_serializing_if = "Option::is_none")]
+    pub platforms: Option<Vec<String>>,
+    #[serde(rename = "activated_features", skip_serializing_if = "Option::is_none")]
+    pub activated_features: Option<Vec<String>>,
     #[serde(rename = "node")]
     pub node: RelatedNode,
 }
@@ -42,6 +106,236 @@ pub struct RelatedNode {
     pub name: String,
     #[serde(rename = "requirement")]
     pub requirement: String,
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CycleEdge {
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "from")]
+    pub from: NodeRef,
+    #[serde(rename = "to")]
+    pub to: NodeRef,
+}
+
+#[derive(Serialize)]
+pub struct NodeRef {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+}
+
+#[derive(Serialize)]
+pub struct Summary {
+    #[serde(rename = "crates")]
+    pub crates: usize,
+    #[serde(rename = "packages")]
+    pub packages: usize,
+    #[serde(rename = "max_depth")]
+    pub max_depth: usize,
+    #[serde(rename = "longest_chain")]
+    pub longest_chain: Vec<NodeRef>,
+    #[serde(rename = "edges")]
+    pub edges: BTreeMap<String, usize>,
+    #[serde(rename = "nodes")]
+    pub nodes: Vec<SummaryNode>,
+    #[serde(rename = "direct_dependencies")]
+    pub direct_dependencies: Vec<SummaryWeight>,
+}
+
+#[derive(Serialize)]
+pub struct SummaryNode {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+    #[serde(rename = "depth")]
+    pub depth: usize,
+    #[serde(rename = "fan_in")]
+    pub fan_in: usize,
+    #[serde(rename = "fan_out")]
+    pub fan_out: usize,
+}
+
+#[derive(Serialize)]
+pub struct SummaryWeight {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+    #[serde(rename = "weight")]
+    pub weight: usize,
+}
+
+#[derive(Serialize)]
+pub struct LicenseGroup {
+    #[serde(rename = "expression")]
+    pub expression: Option<String>,
+    #[serde(rename = "licenses")]
+    pub licenses: Option<Vec<String>>,
+    #[serde(rename = "crates")]
+    pub crates: Vec<NodeRef>,
+}
+
+impl LicenseGroup {
+    pub(crate) fn new(graph: &DependencyGraph, license: &DependencyLicense) -> Self {
+        LicenseGroup {
+            expression: license.expression.clone(),
+            licenses: license
+                .license
+                .as_ref()
+                .map(|license| license.licenses().into_iter().map(str::to_owned).collect()),
+            crates: license
+                .nodes
+                .iter()
+                .map(|node| NodeRef::from(&graph.nodes[*node]))
+                .collect(),
+        }
+    }
+}
+
+impl Summary {
+    fn new(graph: &DependencyGraph, summary: &DependencySummary) -> Self {
+        Summary {
+            crates: summary.crates,
+            packages: summary.packages,
+            max_depth: summary.max_depth,
+            longest_chain: graph
+                .nodes
+                .first()
+                .into_iter()
+                .chain(
+                    summary
+                        .longest_chain
+                        .iter()
+                        .map(|edge| &graph.nodes[edge.to]),
+                )
+                .map(NodeRef::from)
+                .collect(),
+            edges: summary
+                .edges
+                .iter()
+                .map(|(type_, count)| (type_.as_str().to_owned(), *count))
+                .collect(),
+            nodes: graph
+                .nodes
+                .iter()
+                .zip(&summary.nodes)
+                .map(|(node, metrics)| SummaryNode {
+                    name: node.metadata.name.as_str().to_owned(),
+                    version: node.metadata.version.as_str().to_owned(),
+                    depth: metrics.depth,
+                    fan_in: metrics.fan_in,
+                    fan_out: metrics.fan_out,
+                })
+                .collect(),
+            direct_dependencies: summary
+                .direct_dependencies
+                .iter()
+                .map(|dependency| {
+                    let node = &graph.nodes[dependency.node];
+                    SummaryWeight {
+                        name: node.metadata.name.as_str().to_owned(),
+                        version: node.metadata.version.as_str().to_owned(),
+                        weight: dependency.weight,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&DependencyNode> for NodeRef {
+    fn from(node: &DependencyNode) -> Self {
+        NodeRef {
+            name: node.metadata.name.as_str().to_owned(),
+            version: node.metadata.version.as_str().to_owned(),
+        }
+    }
+}
+
+impl CycleEdge {
+    fn new(graph: &DependencyGraph, edge: &DependencyEdge) -> Self {
+        let from = &graph.nodes[edge.from];
+        let dependency = &from.metadata.dependencies[edge.dependency];
+
+        CycleEdge {
+            relationship: format!("dependency.{}", dependency.type_.as_str()),
+            from: NodeRef::from(from),
+            to: NodeRef::from(&graph.nodes[edge.to]),
+        }
+    }
+}
+
+impl From<&CrateAdvisory> for NodeAdvisory {
+    fn from(advisory: &CrateAdvisory) -> Self {
+        NodeAdvisory {
+            id: advisory.id.clone(),
+            severity: advisory
+                .severity
+                .as_ref()
+                .map(|severity| severity.as_str().to_owned()),
+            informational: advisory.informational.clone(),
+            patched: advisory
+                .patched
+                .iter()
+                .map(|requirement| requirement.as_str().to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl From<&DependencyNode> for Node {
+    fn from(node: &DependencyNode) -> Self {
+        Node {
+            name: node.metadata.name.as_str().to_owned(),
+            version: node.metadata.version.as_str().to_owned(),
+            yanked: node.yanked,
+            license: node.license.clone(),
+            latest_version: None,
+            advisories: None,
+            features: node
+                .features
+                .as_ref()
+                .map(|features| features.iter().cloned().collect()),
+            edges: node
+                .metadata
+                .dependencies
+                .iter()
+                .zip(&node.resolved_versions)
+                .zip(&node.dependency_features)
+                .map(
+                    |((dependency, resolved_version), activated_features)| Edge {
+                        relationship: format!("dependency.{}", dependency.type_.as_str()),
+                        optional: dependency.optional,
+                        default_features: dependency.default_features,
+                        features: dependency.features.clone(),
+                        target: dependency.target.as_ref().map(|target| target.to_string()),
+                        platforms: dependency.target.as_ref().map(|target| {
+                            target
+                                .platforms()
+                                .iter()
+                                .map(|platform| platform.as_str().to_owned())
+                                .collect()
+                        }),
+                        activated_features: activated_features
+                            .as_ref()
+                            .map(|features| features.iter().cloned().collect()),
+                        node: RelatedNode {
+                            name: dependency.name.as_str().to_owned(),
+                            requirement: dependency.requirement.as_str().to_owned(),
+                            version: resolved_version
+                                .as_ref()
+                                .map(|version| version.as_str().to_owned()),
+                        },
+                    },
+                )
+                .collect(),
+        }
+    }
 }
 
 #[tracing::instrument(
@@ -49,6 +343,14 @@ pub struct RelatedNode {
     fields(
         crate_name = %query.crate_name,
         crate_version = %query.crate_version,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+        cycles = ?query.cycles,
+        summary = ?query.summary,
+        licenses = ?query.licenses,
     ),
 )]
 pub async fn dependency_query(
@@ -56,47 +358,161 @@ pub async fn dependency_query(
     crates_io_client: web::Data<CratesIoClient>,
     postgres_client: web::Data<PostgresClient>,
 ) -> Result<HttpResponse, HttpResponse> {
-    let name = CrateName::parse(&query.crate_name)?;
-    let version = CrateVersion::parse(&query.crate_version)?;
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
 
-    let metadata = if let Some(metadata) = postgres_client
-        .get_crate_metadata(&name, &version)
+    let advisories = postgres_client
+        .get_crate_advisories(
+            &graph
+                .nodes
+                .iter()
+                .map(|node| node.metadata.name.clone())
+                .collect::<Vec<_>>(),
+        )
         .await
-        .unwrap()
-    {
-        metadata
-    } else {
-        let metadata = crates_io_client
-            .get_ref()
-            .dependencies(&name, &version)
-            .await
-            .ok_or_else(|| HttpResponse::NotFound().finish())?;
-
-        postgres_client
-            .save_crate_metadata(&metadata)
-            .await
-            .unwrap();
-
-        metadata
-    };
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
 
     let json = Response {
-        data: vec![Node {
-            name: metadata.name.as_str().to_owned(),
-            version: metadata.version.as_str().to_owned(),
-            edges: metadata
-                .dependencies
-                .iter()
-                .map(|dependency| Edge {
-                    relationship: format!("dependency.{}", dependency.type_.as_str()),
-                    node: RelatedNode {
-                        name: dependency.name.as_str().to_owned(),
-                        requirement: dependency.requirement.as_str().to_owned(),
-                    },
-                })
-                .collect(),
-        }],
+        data: graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let mut json = Node::from(node);
+
+                let affecting = advisories
+                    .iter()
+                    .filter(|advisory| {
+                        advisory.name == node.metadata.name
+                            && advisory.affects(&node.metadata.version)
+                    })
+                    .map(NodeAdvisory::from)
+                    .collect::<Vec<_>>();
+                if !affecting.is_empty() {
+                    json.advisories = Some(affecting);
+                }
+
+                json
+            })
+            .collect(),
+        cycles: if query.cycles.unwrap_or_default() {
+            Some(
+                graph
+                    .cycles()
+                    .iter()
+                    .map(|cycle| {
+                        cycle
+                            .edges
+                            .iter()
+                            .map(|edge| CycleEdge::new(&graph, edge))
+                            .collect()
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        },
+        duplicates: None,
+        summary: if query.summary.unwrap_or_default() {
+            Some(Summary::new(&graph, &graph.summary()))
+        } else {
+            None
+        },
+        licenses: if query.licenses.unwrap_or_default() {
+            Some(
+                graph
+                    .licenses()
+                    .iter()
+                    .map(|license| LicenseGroup::new(&graph, license))
+                    .collect(),
+            )
+        } else {
+            None
+        },
     };
 
     Ok(HttpResponse::Ok().json(&json))
 }
+
+/// Resolves the dependency graph described by the query, shared by the routes that report on it.
+pub(crate) async fn resolve_graph(
+    query: &Query,
+    crates_io_client: &CratesIoClient,
+    postgres_client: &PostgresClient,
+) -> Result<DependencyGraph, HttpResponse> {
+    let mut resolver = Resolver::new(crates_io_client, postgres_client);
+
+    resolve_graph_with(query, &mut resolver).await
+}
+
+/// Resolves the dependency graph described by the query with the given resolver, for routes that
+/// go on to look up more releases.
+pub(crate) async fn resolve_graph_with(
+    query: &Query,
+    resolver: &mut Resolver<'_>,
+) -> Result<DependencyGraph, HttpResponse> {
+    let name =
+        CrateName::parse(&query.crate_name).map_err(|e| HttpResponse::BadRequest().body(e))?;
+    let selector = CrateVersionSelector::parse(&query.crate_version)
+        .map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let options = resolve_options(
+        query.depth.as_deref(),
+        query.target.as_deref(),
+        query.features.as_deref(),
+        query.no_default_features,
+        query.dev_dependencies,
+    )?;
+
+    let version = resolver
+        .select_version(&name, &selector)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?
+        .ok_or_else(|| HttpResponse::NotFound().finish())?;
+
+    resolver
+        .resolve(&name, &version, &options)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?
+        .ok_or_else(|| HttpResponse::NotFound().finish())
+}
+
+/// Builds the resolve options from the query parameters shared by the routes that resolve graphs.
+pub(crate) fn resolve_options(
+    depth: Option<&str>,
+    target: Option<&str>,
+    features: Option<&str>,
+    no_default_features: Option<bool>,
+    dev_dependencies: Option<bool>,
+) -> Result<ResolveOptions, HttpResponse> {
+    let mut options = ResolveOptions::default();
+
+    if let Some(depth) = depth {
+        options.depth = Depth::parse(depth).map_err(|e| HttpResponse::BadRequest().body(e))?;
+    }
+
+    if let Some(target) = target {
+        options.platform =
+            Some(TargetPlatform::parse(target).map_err(|e| HttpResponse::BadRequest().body(e))?);
+    }
+
+    if features.is_some() || no_default_features.is_some() {
+        let mut features = features
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|feature| !feature.is_empty())
+            .map(str::to_owned)
+            .collect::<BTreeSet<_>>();
+
+        if !no_default_features.unwrap_or_default() {
+            features.insert("default".to_owned());
+        }
+
+        options.features = Some(features);
+    }
+
+    if let Some(dev_dependencies) = dev_dependencies {
+        options.dev_dependencies = dev_dependencies;
+    }
+
+    Ok(options)
+}
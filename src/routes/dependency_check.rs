@@ -0,0 +1,152 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CrateLicense, CratePolicy};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::{DependencyGraph, PolicyRule, PolicyViolation};
+use crate::routes::{resolve_graph, NodeRef, PathEdge, Query, RelatedNode};
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CheckResponse {
+    #[serde(rename = "violations")]
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Serialize)]
+pub struct Violation {
+    #[serde(rename = "rule")]
+    pub rule: String,
+    #[serde(rename = "message")]
+    pub message: String,
+    #[serde(rename = "node")]
+    pub node: NodeRef,
+    #[serde(rename = "dependency", skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<RelatedNode>,
+    #[serde(rename = "path")]
+    pub path: Option<Vec<PathEdge>>,
+}
+
+impl Violation {
+    fn new(graph: &DependencyGraph, policy: &CratePolicy, violation: &PolicyViolation) -> Self {
+        let node = &graph.nodes[violation.node];
+        let name = node.metadata.name.as_str();
+        let version = node.metadata.version.as_str();
+        let dependency = violation
+            .dependency
+            .map(|dependency| (&node.metadata.dependencies[dependency], dependency));
+
+        let message = match (violation.rule, &dependency) {
+            (PolicyRule::License, _) => match &node.license {
+                None => format!("{} {} has no license expression.", name, version),
+                Some(license) if CrateLicense::parse(license).is_err() => format!(
+                    "{} {} is licensed under `{}`, which is not a valid SPDX expression.",
+                    name, version, license
+                ),
+                Some(license) => format!(
+                    "{} {} is licensed under `{}`, which the policy does not allow.",
+                    name, version, license
+                ),
+            },
+            (PolicyRule::Ban, _) => match policy
+                .ban(&node.metadata.name, &node.metadata.version)
+                .and_then(|ban| ban.requirement.as_ref())
+            {
+                Some(requirement) => format!(
+                    "{} {} is banned by the policy for versions `{}`.",
+                    name,
+                    version,
+                    requirement.as_str()
+                ),
+                None => format!("{} {} is banned by the policy.", name, version),
+            },
+            (PolicyRule::Duplicate, _) => format!(
+                "{} {} is one of several semver incompatible versions of {}.",
+                name, version, name
+            ),
+            (PolicyRule::Wildcard, Some((dependency, _))) => format!(
+                "{} {} depends on {} with the wildcard requirement `{}`.",
+                name,
+                version,
+                dependency.name.as_str(),
+                dependency.requirement.as_str()
+            ),
+            (PolicyRule::Wildcard, None) => {
+                format!("{} {} has a wildcard requirement.", name, version)
+            }
+        };
+
+        Violation {
+            rule: violation.rule.as_str().to_owned(),
+            message,
+            node: NodeRef::from(node),
+            dependency: dependency.map(|(dependency, index)| RelatedNode {
+                name: dependency.name.as_str().to_owned(),
+                requirement: dependency.requirement.as_str().to_owned(),
+                version: node.resolved_versions[index]
+                    .as_ref()
+                    .map(|version| version.as_str().to_owned()),
+            }),
+            path: violation.path.as_ref().map(|path| {
+                path.edges
+                    .iter()
+                    .map(|edge| PathEdge::new(graph, edge))
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Checks the graph against the policy. Violations are answered with `422 Unprocessable Entity`,
+/// so CI jobs calling the service fail on them.
+pub(crate) fn check_graph(
+    graph: &DependencyGraph,
+    policy: &CratePolicy,
+    roots: &[usize],
+) -> HttpResponse {
+    let json = CheckResponse {
+        violations: graph
+            .check(policy, roots)
+            .iter()
+            .map(|violation| Violation::new(graph, policy, violation))
+            .collect(),
+    };
+
+    if json.violations.is_empty() {
+        HttpResponse::Ok().json(&json)
+    } else {
+        HttpResponse::UnprocessableEntity().json(&json)
+    }
+}
+
+/// Checks the dependency graph against the configured policy. The whole graph is resolved
+/// unless a depth is given.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, policy, query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_check_query(
+    query: web::Query<Query>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+    policy: web::Data<Option<CratePolicy>>,
+) -> Result<HttpResponse, HttpResponse> {
+    let policy = policy
+        .get_ref()
+        .as_ref()
+        .ok_or_else(|| HttpResponse::ServiceUnavailable().finish())?;
+
+    let mut query = query.into_inner();
+    query.depth.get_or_insert_with(|| "all".to_owned());
+
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
+
+    Ok(check_graph(&graph, policy, &[0]))
+}
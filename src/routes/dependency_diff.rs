@@ -0,0 +1,255 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{
+    CrateDependency, CrateDependencyDiff, CrateName, CrateVersion, CrateVersionSelector,
+};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::{DependencyPackageChange, Resolver};
+use crate::routes::resolve_options;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct DiffQuery {
+    #[serde(rename = "name")]
+    pub crate_name: String,
+    #[serde(rename = "from")]
+    pub from: String,
+    #[serde(rename = "to")]
+    pub to: String,
+    #[serde(rename = "transitive")]
+    pub transitive: Option<bool>,
+    #[serde(rename = "target")]
+    pub target: Option<String>,
+    #[serde(rename = "features")]
+    pub features: Option<String>,
+    #[serde(rename = "no_default_features")]
+    pub no_default_features: Option<bool>,
+    #[serde(rename = "dev_dependencies")]
+    pub dev_dependencies: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct DiffResponse {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "from")]
+    pub from: String,
+    #[serde(rename = "to")]
+    pub to: String,
+    #[serde(rename = "dependencies")]
+    pub dependencies: DependenciesDiff,
+    #[serde(rename = "packages", skip_serializing_if = "Option::is_none")]
+    pub packages: Option<PackagesDiff>,
+}
+
+#[derive(Serialize)]
+pub struct DependenciesDiff {
+    #[serde(rename = "added")]
+    pub added: Vec<DiffDependency>,
+    #[serde(rename = "removed")]
+    pub removed: Vec<DiffDependency>,
+    #[serde(rename = "changed")]
+    pub changed: Vec<DependencyChange>,
+}
+
+#[derive(Serialize)]
+pub struct DependencyChange {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "from")]
+    pub from: DiffDependency,
+    #[serde(rename = "to")]
+    pub to: DiffDependency,
+}
+
+#[derive(Serialize)]
+pub struct DiffDependency {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+    #[serde(rename = "optional")]
+    pub optional: bool,
+    #[serde(rename = "default_features")]
+    pub default_features: bool,
+    #[serde(rename = "features")]
+    pub features: Vec<String>,
+    #[serde(rename = "target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PackagesDiff {
+    #[serde(rename = "added")]
+    pub added: Vec<PackageChange>,
+    #[serde(rename = "removed")]
+    pub removed: Vec<PackageChange>,
+    #[serde(rename = "changed")]
+    pub changed: Vec<PackageChange>,
+}
+
+#[derive(Serialize)]
+pub struct PackageChange {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "from")]
+    pub from: Vec<String>,
+    #[serde(rename = "to")]
+    pub to: Vec<String>,
+}
+
+impl From<&CrateDependency> for DiffDependency {
+    fn from(dependency: &CrateDependency) -> Self {
+        DiffDependency {
+            name: dependency.name.as_str().to_owned(),
+            relationship: format!("dependency.{}", dependency.type_.as_str()),
+            requirement: dependency.requirement.as_str().to_owned(),
+            optional: dependency.optional,
+            default_features: dependency.default_features,
+            features: dependency.features.clone(),
+            target: dependency.target.as_ref().map(|target| target.to_string()),
+        }
+    }
+}
+
+impl From<&CrateDependencyDiff> for DependenciesDiff {
+    fn from(diff: &CrateDependencyDiff) -> Self {
+        DependenciesDiff {
+            added: diff.added.iter().map(DiffDependency::from).collect(),
+            removed: diff.removed.iter().map(DiffDependency::from).collect(),
+            changed: diff
+                .changed
+                .iter()
+                .map(|change| DependencyChange {
+                    name: change.to.name.as_str().to_owned(),
+                    from: DiffDependency::from(&change.from),
+                    to: DiffDependency::from(&change.to),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&[DependencyPackageChange]> for PackagesDiff {
+    fn from(changes: &[DependencyPackageChange]) -> Self {
+        let mut diff = PackagesDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        };
+
+        for change in changes {
+            let versions = |versions: &[CrateVersion]| {
+                versions
+                    .iter()
+                    .map(|version| version.as_str().to_owned())
+                    .collect()
+            };
+
+            let json = PackageChange {
+                name: change.name.as_str().to_owned(),
+                from: versions(&change.from),
+                to: versions(&change.to),
+            };
+
+            if change.from.is_empty() {
+                diff.added.push(json);
+            } else if change.to.is_empty() {
+                diff.removed.push(json);
+            } else {
+                diff.changed.push(json);
+            }
+        }
+
+        diff
+    }
+}
+
+/// Compares the dependencies declared by two versions of a crate. The transitive mode also
+/// compares the crates their whole dependency graphs resolve to.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query),
+    fields(
+        crate_name = %query.crate_name,
+        from = %query.from,
+        to = %query.to,
+        transitive = ?query.transitive,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_diff_query(
+    query: web::Query<DiffQuery>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let name =
+        CrateName::parse(&query.crate_name).map_err(|e| HttpResponse::BadRequest().body(e))?;
+    let from =
+        CrateVersionSelector::parse(&query.from).map_err(|e| HttpResponse::BadRequest().body(e))?;
+    let to =
+        CrateVersionSelector::parse(&query.to).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let options = resolve_options(
+        Some("all"),
+        query.target.as_deref(),
+        query.features.as_deref(),
+        query.no_default_features,
+        query.dev_dependencies,
+    )?;
+
+    let mut resolver = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref());
+
+    let mut metadata = Vec::with_capacity(2);
+    for selector in &[from, to] {
+        let version = resolver
+            .select_version(&name, selector)
+            .await
+            .map_err(|_| HttpResponse::InternalServerError().finish())?
+            .ok_or_else(|| HttpResponse::NotFound().finish())?;
+
+        metadata.push(
+            resolver
+                .crate_metadata(&name, &version)
+                .await
+                .map_err(|_| HttpResponse::InternalServerError().finish())?
+                .ok_or_else(|| HttpResponse::NotFound().finish())?,
+        );
+    }
+
+    let (from, to) = (&metadata[0], &metadata[1]);
+
+    let packages = if query.transitive.unwrap_or_default() {
+        let mut graphs = Vec::with_capacity(2);
+        for metadata in &[from, to] {
+            graphs.push(
+                resolver
+                    .resolve(&metadata.name, &metadata.version, &options)
+                    .await
+                    .map_err(|_| HttpResponse::InternalServerError().finish())?
+                    .ok_or_else(|| HttpResponse::NotFound().finish())?,
+            );
+        }
+
+        Some(PackagesDiff::from(graphs[0].diff(&graphs[1]).as_slice()))
+    } else {
+        None
+    };
+
+    let json = DiffResponse {
+        name: from.name.as_str().to_owned(),
+        from: from.version.as_str().to_owned(),
+        to: to.version.as_str().to_owned(),
+        dependencies: DependenciesDiff::from(&CrateDependencyDiff::new(
+            &from.dependencies,
+            &to.dependencies,
+        )),
+        packages,
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
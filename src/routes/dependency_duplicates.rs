@@ -0,0 +1,129 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::postgres_client::PostgresClient;
+use crate::resolver::{
+    DependencyDuplicate, DependencyDuplicateVersion, DependencyEdge, DependencyGraph,
+};
+use crate::routes::{resolve_graph, Query};
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct DuplicatesResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<DuplicateNode>,
+}
+
+#[derive(Serialize)]
+pub struct DuplicateNode {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "versions")]
+    pub versions: Vec<DuplicateVersion>,
+}
+
+#[derive(Serialize)]
+pub struct DuplicateVersion {
+    #[serde(rename = "version")]
+    pub version: String,
+    #[serde(rename = "dependents")]
+    pub dependents: Vec<DuplicateEdge>,
+}
+
+#[derive(Serialize)]
+pub struct DuplicateEdge {
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+    #[serde(rename = "node")]
+    pub node: DependentNode,
+}
+
+#[derive(Serialize)]
+pub struct DependentNode {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+}
+
+impl DuplicateNode {
+    pub(crate) fn new(graph: &DependencyGraph, duplicate: &DependencyDuplicate) -> Self {
+        DuplicateNode {
+            name: duplicate.name.as_str().to_owned(),
+            versions: duplicate
+                .versions
+                .iter()
+                .map(|version| DuplicateVersion::new(graph, version))
+                .collect(),
+        }
+    }
+}
+
+impl DuplicateVersion {
+    fn new(graph: &DependencyGraph, version: &DependencyDuplicateVersion) -> Self {
+        DuplicateVersion {
+            version: graph.nodes[version.node]
+                .metadata
+                .version
+                .as_str()
+                .to_owned(),
+            dependents: version
+                .dependents
+                .iter()
+                .map(|edge| DuplicateEdge::new(graph, edge))
+                .collect(),
+        }
+    }
+}
+
+impl DuplicateEdge {
+    fn new(graph: &DependencyGraph, edge: &DependencyEdge) -> Self {
+        let from = &graph.nodes[edge.from].metadata;
+        let dependency = &from.dependencies[edge.dependency];
+
+        DuplicateEdge {
+            relationship: format!("dependency.{}", dependency.type_.as_str()),
+            requirement: dependency.requirement.as_str().to_owned(),
+            node: DependentNode {
+                name: from.name.as_str().to_owned(),
+                version: from.version.as_str().to_owned(),
+            },
+        }
+    }
+}
+
+/// Reports the crates the graph holds at semver incompatible versions. The whole graph is resolved
+/// unless a depth is given.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_duplicates_query(
+    query: web::Query<Query>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let mut query = query.into_inner();
+    query.depth.get_or_insert_with(|| "all".to_owned());
+
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
+
+    let json = DuplicatesResponse {
+        data: graph
+            .duplicates()
+            .iter()
+            .map(|duplicate| DuplicateNode::new(&graph, duplicate))
+            .collect(),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
@@ -0,0 +1,62 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CargoLock, CargoLockPackage, CRATES_IO_SOURCE};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::DependencyGraph;
+use crate::routes::{resolve_graph, Query};
+use actix_web::{web, HttpResponse};
+
+impl From<&DependencyGraph> for CargoLock {
+    fn from(graph: &DependencyGraph) -> Self {
+        let mut packages = graph
+            .nodes
+            .iter()
+            .map(|node| CargoLockPackage {
+                name: node.metadata.name.clone(),
+                version: node.metadata.version.clone(),
+                source: Some(CRATES_IO_SOURCE.to_owned()),
+                checksum: node.checksum.clone(),
+                dependencies: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        for edge in graph.edges() {
+            let to = &graph.nodes[edge.to].metadata;
+            packages[edge.from]
+                .dependencies
+                .push((to.name.clone(), to.version.clone()));
+        }
+
+        CargoLock::new(packages)
+    }
+}
+
+/// The whole graph is resolved unless a depth is given, since a lockfile pins every package.
+/// Features are resolved from the default features unless features are given, so optional
+/// dependencies Cargo would not build are left out.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_lockfile_query(
+    query: web::Query<Query>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let mut query = query.into_inner();
+    query.depth.get_or_insert_with(|| "all".to_owned());
+    query.features.get_or_insert_with(String::new);
+
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/toml")
+        .body(CargoLock::from(&graph).to_string()))
+}
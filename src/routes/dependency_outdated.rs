@@ -0,0 +1,110 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::CrateVersion;
+use crate::postgres_client::PostgresClient;
+use crate::resolver::{DependencyGraph, DependencyOutdated, Resolver};
+use crate::routes::{resolve_graph_with, NodeRef, Query};
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct OutdatedQuery {
+    #[serde(rename = "transitive")]
+    pub transitive: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct OutdatedResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<OutdatedNode>,
+}
+
+#[derive(Serialize)]
+pub struct OutdatedNode {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+    #[serde(rename = "dependent")]
+    pub dependent: NodeRef,
+    #[serde(rename = "resolved")]
+    pub resolved: Option<String>,
+    #[serde(rename = "compatible")]
+    pub compatible: Option<String>,
+    #[serde(rename = "latest")]
+    pub latest: Option<String>,
+    #[serde(rename = "requires_bump")]
+    pub requires_bump: bool,
+}
+
+impl OutdatedNode {
+    fn new(graph: &DependencyGraph, outdated: &DependencyOutdated) -> Self {
+        let node = &graph.nodes[outdated.node];
+        let dependency = &node.metadata.dependencies[outdated.dependency];
+        let version = |version: &Option<CrateVersion>| {
+            version.as_ref().map(|version| version.as_str().to_owned())
+        };
+
+        OutdatedNode {
+            name: dependency.name.as_str().to_owned(),
+            relationship: format!("dependency.{}", dependency.type_.as_str()),
+            requirement: dependency.requirement.as_str().to_owned(),
+            dependent: NodeRef::from(node),
+            resolved: version(&outdated.outdated.resolved),
+            compatible: version(&outdated.outdated.compatible),
+            latest: version(&outdated.outdated.latest),
+            requires_bump: outdated.outdated.requires_bump,
+        }
+    }
+}
+
+/// Reports how far behind the requirements of the direct dependencies are, or of every crate in
+/// the graph when `transitive`. Direct dependencies are reported without resolving the graph any
+/// deeper, so the depth only applies to transitive reports, which resolve the whole graph unless
+/// a depth is given.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query, outdated_query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        transitive = ?outdated_query.transitive,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_outdated_query(
+    query: web::Query<Query>,
+    outdated_query: web::Query<OutdatedQuery>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let transitive = outdated_query.transitive.unwrap_or(false);
+
+    let mut query = query.into_inner();
+    if transitive {
+        query.depth.get_or_insert_with(|| "all".to_owned());
+    } else {
+        query.depth = Some("0".to_owned());
+    }
+
+    let mut resolver = Resolver::new(&crates_io_client, &postgres_client);
+    let graph = resolve_graph_with(&query, &mut resolver).await?;
+
+    let outdated = resolver
+        .outdated(&graph, transitive)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    let json = OutdatedResponse {
+        data: outdated
+            .iter()
+            .map(|outdated| OutdatedNode::new(&graph, outdated))
+            .collect(),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
@@ -0,0 +1,100 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::CrateName;
+use crate::postgres_client::PostgresClient;
+use crate::resolver::{DependencyEdge, DependencyGraph};
+use crate::routes::{resolve_graph, NodeRef, Query};
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+/// Paths multiply with every crate depending on the crate before it, so only this many are
+/// returned.
+const MAX_PATHS: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct PathsQuery {
+    #[serde(rename = "dependency")]
+    pub dependency: String,
+}
+
+#[derive(Serialize)]
+pub struct PathsResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<Vec<PathEdge>>,
+    #[serde(rename = "truncated")]
+    pub truncated: bool,
+}
+
+#[derive(Serialize)]
+pub struct PathEdge {
+    #[serde(rename = "relationship")]
+    pub relationship: String,
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+    #[serde(rename = "from")]
+    pub from: NodeRef,
+    #[serde(rename = "to")]
+    pub to: NodeRef,
+}
+
+impl PathEdge {
+    pub(crate) fn new(graph: &DependencyGraph, edge: &DependencyEdge) -> Self {
+        let from = &graph.nodes[edge.from];
+        let dependency = &from.metadata.dependencies[edge.dependency];
+
+        PathEdge {
+            relationship: format!("dependency.{}", dependency.type_.as_str()),
+            requirement: dependency.requirement.as_str().to_owned(),
+            from: NodeRef::from(from),
+            to: NodeRef::from(&graph.nodes[edge.to]),
+        }
+    }
+}
+
+/// Explains why a crate is part of the dependency graph, like `cargo tree --invert`. The whole
+/// graph is resolved unless a depth is given.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query, paths_query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        dependency = %paths_query.dependency,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_paths_query(
+    query: web::Query<Query>,
+    paths_query: web::Query<PathsQuery>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let dependency = CrateName::parse(&paths_query.dependency)
+        .map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let mut query = query.into_inner();
+    query.depth.get_or_insert_with(|| "all".to_owned());
+
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
+
+    let mut paths = graph.paths(&dependency, MAX_PATHS + 1);
+    let truncated = paths.len() > MAX_PATHS;
+    paths.truncate(MAX_PATHS);
+
+    let json = PathsResponse {
+        data: paths
+            .iter()
+            .map(|path| {
+                path.edges
+                    .iter()
+                    .map(|edge| PathEdge::new(&graph, edge))
+                    .collect()
+            })
+            .collect(),
+        truncated,
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
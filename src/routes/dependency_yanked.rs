@@ -0,0 +1,58 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::postgres_client::PostgresClient;
+use crate::routes::{resolve_graph, Query};
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct YankedResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<YankedNode>,
+}
+
+#[derive(Serialize)]
+pub struct YankedNode {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "version")]
+    pub version: String,
+}
+
+/// Reports the crates of the graph whose version was yanked. The whole graph is resolved unless a
+/// depth is given.
+#[tracing::instrument(
+    skip(crates_io_client, postgres_client, query),
+    fields(
+        crate_name = %query.crate_name,
+        crate_version = %query.crate_version,
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+    ),
+)]
+pub async fn dependency_yanked_query(
+    query: web::Query<Query>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let mut query = query.into_inner();
+    query.depth.get_or_insert_with(|| "all".to_owned());
+
+    let graph = resolve_graph(&query, &crates_io_client, &postgres_client).await?;
+
+    let json = YankedResponse {
+        data: graph
+            .nodes
+            .iter()
+            .filter(|node| node.yanked == Some(true))
+            .map(|node| YankedNode {
+                name: node.metadata.name.as_str().to_owned(),
+                version: node.metadata.version.as_str().to_owned(),
+            })
+            .collect(),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
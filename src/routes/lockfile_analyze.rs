@@ -0,0 +1,71 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CargoLock, CrateVersionSelector};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::Resolver;
+use crate::routes::{DuplicateNode, LicenseGroup, Node, Response};
+use actix_web::{web, HttpResponse};
+
+/// Cargo.lock files of large workspaces easily exceed the default payload limit.
+pub const LOCKFILE_PAYLOAD_LIMIT: usize = 4 * 1024 * 1024;
+
+#[tracing::instrument(
+    skip(body, crates_io_client, postgres_client),
+    fields(
+        length = body.len(),
+    ),
+)]
+pub async fn lockfile_analyze(
+    body: String,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let lockfile = CargoLock::parse(&body).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let mut resolver = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref());
+
+    let graph = resolver
+        .resolve_lockfile(&lockfile)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    let mut data = Vec::with_capacity(graph.nodes.len());
+
+    for node in &graph.nodes {
+        let mut json = Node::from(node);
+
+        // Only versions crates.io knows about have a yanked status, other packages are not
+        // published there and may share their name with an unrelated crate.
+        if node.yanked.is_some() {
+            json.latest_version = resolver
+                .select_version(&node.metadata.name, &CrateVersionSelector::Latest)
+                .await
+                .map_err(|_| HttpResponse::InternalServerError().finish())?
+                .filter(|latest| latest > &node.metadata.version)
+                .map(|latest| latest.as_str().to_owned());
+        }
+
+        data.push(json);
+    }
+
+    let json = Response {
+        data,
+        cycles: None,
+        duplicates: Some(
+            graph
+                .duplicates()
+                .iter()
+                .map(|duplicate| DuplicateNode::new(&graph, duplicate))
+                .collect(),
+        ),
+        summary: None,
+        licenses: Some(
+            graph
+                .licenses()
+                .iter()
+                .map(|license| LicenseGroup::new(&graph, license))
+                .collect(),
+        ),
+    };
+
+    Ok(HttpResponse::Ok().json(&json))
+}
@@ -0,0 +1,43 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CargoLock, CratePolicy};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::Resolver;
+use crate::routes::check_graph;
+use actix_web::{web, HttpResponse};
+
+/// Checks the dependency graph pinned by a lockfile against the configured policy. Paths lead
+/// from the packages without a source, which are the local packages of the workspace.
+#[tracing::instrument(
+    skip(body, crates_io_client, postgres_client, policy),
+    fields(
+        length = body.len(),
+    ),
+)]
+pub async fn lockfile_check(
+    body: String,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+    policy: web::Data<Option<CratePolicy>>,
+) -> Result<HttpResponse, HttpResponse> {
+    let policy = policy
+        .get_ref()
+        .as_ref()
+        .ok_or_else(|| HttpResponse::ServiceUnavailable().finish())?;
+
+    let lockfile = CargoLock::parse(&body).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let graph = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref())
+        .resolve_lockfile(&lockfile)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    let roots = lockfile
+        .packages
+        .iter()
+        .enumerate()
+        .filter(|(_, package)| package.source.is_none())
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    Ok(check_graph(&graph, policy, &roots))
+}
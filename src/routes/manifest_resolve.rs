@@ -0,0 +1,92 @@
+use crate::crates_io_client::CratesIoClient;
+use crate::domain::{CargoLock, CrateManifest, GraphFormat};
+use crate::postgres_client::PostgresClient;
+use crate::resolver::Resolver;
+use crate::routes::{resolve_options, Node, Response};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use std::convert::TryFrom;
+
+#[derive(Debug, Deserialize)]
+pub struct ManifestQuery {
+    #[serde(rename = "depth")]
+    pub depth: Option<String>,
+    #[serde(rename = "target")]
+    pub target: Option<String>,
+    #[serde(rename = "features")]
+    pub features: Option<String>,
+    #[serde(rename = "no_default_features")]
+    pub no_default_features: Option<bool>,
+    #[serde(rename = "dev_dependencies")]
+    pub dev_dependencies: Option<bool>,
+    #[serde(rename = "format")]
+    pub format: Option<String>,
+}
+
+/// The whole graph is resolved unless a depth is given, since the manifest is the only thing
+/// known about the root crate.
+#[tracing::instrument(
+    skip(body, crates_io_client, postgres_client, query),
+    fields(
+        length = body.len(),
+        depth = ?query.depth,
+        target = ?query.target,
+        features = ?query.features,
+        no_default_features = ?query.no_default_features,
+        dev_dependencies = ?query.dev_dependencies,
+        format = ?query.format,
+    ),
+)]
+pub async fn manifest_resolve(
+    body: String,
+    query: web::Query<ManifestQuery>,
+    crates_io_client: web::Data<CratesIoClient>,
+    postgres_client: web::Data<PostgresClient>,
+) -> Result<HttpResponse, HttpResponse> {
+    let manifest = CrateManifest::parse(&body).map_err(|e| HttpResponse::BadRequest().body(e))?;
+
+    let format = match &query.format {
+        Some(format) => GraphFormat::try_from(format.as_str())
+            .map_err(|e| HttpResponse::BadRequest().body(e))?,
+        None => GraphFormat::Json,
+    };
+
+    let options = resolve_options(
+        Some(query.depth.as_deref().unwrap_or("all")),
+        query.target.as_deref(),
+        query.features.as_deref(),
+        query.no_default_features,
+        query.dev_dependencies,
+    )?;
+
+    let graph = Resolver::new(crates_io_client.get_ref(), postgres_client.get_ref())
+        .resolve_manifest(&manifest, &options)
+        .await
+        .map_err(|_| HttpResponse::InternalServerError().finish())?;
+
+    match format {
+        GraphFormat::Json => Ok(HttpResponse::Ok().json(&Response {
+            data: graph.nodes.iter().map(Node::from).collect(),
+            cycles: None,
+            duplicates: None,
+            summary: None,
+            licenses: None,
+        })),
+        GraphFormat::Lockfile => {
+            let mut lockfile = CargoLock::from(&graph);
+
+            // The manifest describes a local package, which Cargo locks without a source.
+            for package in &mut lockfile.packages {
+                if package.name == manifest.metadata.name
+                    && package.version == manifest.metadata.version
+                {
+                    package.source = None;
+                }
+            }
+
+            Ok(HttpResponse::Ok()
+                .content_type("application/toml")
+                .body(lockfile.to_string()))
+        }
+    }
+}
@@ -1,5 +1,31 @@
+mod advisories_reload;
+mod crate_reverse_dependencies;
+mod crate_versions;
 mod dependency;
+mod dependency_check;
+mod dependency_diff;
+mod dependency_duplicates;
+mod dependency_lockfile;
+mod dependency_outdated;
+mod dependency_paths;
+mod dependency_yanked;
 mod health;
+mod lockfile_analyze;
+mod lockfile_check;
+mod manifest_resolve;
 
+pub use advisories_reload::*;
+pub use crate_reverse_dependencies::*;
+pub use crate_versions::*;
 pub use dependency::*;
+pub use dependency_check::*;
+pub use dependency_diff::*;
+pub use dependency_duplicates::*;
+pub use dependency_lockfile::*;
+pub use dependency_outdated::*;
+pub use dependency_paths::*;
+pub use dependency_yanked::*;
 pub use health::*;
+pub use lockfile_analyze::*;
+pub use lockfile_check::*;
+pub use manifest_resolve::*;
@@ -1,6 +1,12 @@
 use crate::configuration::Configuration;
 use crate::postgres_client::PostgresClient;
-use crate::routes::{dependency_query, health_liveness, health_readiness};
+use crate::routes::{
+    advisories_reload, crate_reverse_dependencies_query, crate_versions_query,
+    dependency_check_query, dependency_diff_query, dependency_duplicates_query,
+    dependency_lockfile_query, dependency_outdated_query, dependency_paths_query, dependency_query,
+    dependency_yanked_query, health_liveness, health_readiness, lockfile_analyze, lockfile_check,
+    manifest_resolve, LOCKFILE_PAYLOAD_LIMIT,
+};
 use actix_web::dev::Server;
 use actix_web::{web, App, HttpServer};
 use tracing_actix_web::TracingLogger;
@@ -17,6 +23,25 @@ pub async fn run(overrides: &[(&str, &str)]) -> (Server, u16, Configuration) {
     let postgres_pool = configuration.postgres.database_pool();
     let postgres_client = PostgresClient::new(postgres_pool.clone());
 
+    // Failures are traced and keep the advisories stored before, until they are reloaded.
+    let advisory_database = configuration.advisories.database();
+    if let Some(advisory_database) = &advisory_database {
+        if let Ok(advisories) = advisory_database.scan() {
+            if let Err(error) = postgres_client.save_crate_advisories(&advisories).await {
+                tracing::warn!(error = %error, "failed to save advisories");
+            }
+        }
+    }
+
+    // Failures are traced and nothing is checked, as if no policy was configured.
+    let policy = configuration.policy.policy().unwrap_or_else(|error| {
+        tracing::warn!(error = %error, "failed to read policy");
+        None
+    });
+
+    let advisories_configuration = web::Data::new(configuration.advisories.clone());
+    let advisory_database = web::Data::new(advisory_database);
+    let policy = web::Data::new(policy);
     let postgres_pool = web::Data::new(postgres_pool);
     let postgres_client = web::Data::new(postgres_client);
 
@@ -36,13 +61,44 @@ pub async fn run(overrides: &[(&str, &str)]) -> (Server, u16, Configuration) {
     let server = HttpServer::new(move || {
         App::new()
             .wrap(TracingLogger)
+            .service(
+                web::scope("/admin").route("/advisories/reload", web::post().to(advisories_reload)),
+            )
             .service(
                 web::scope("/health")
                     .route("/liveness", web::get().to(health_liveness))
                     .route("/readiness", web::get().to(health_readiness)),
             )
-            .service(web::scope("/dependency").route("", web::get().to(dependency_query)))
+            .service(
+                web::scope("/crates")
+                    .route(
+                        "/{name}/reverse-dependencies",
+                        web::get().to(crate_reverse_dependencies_query),
+                    )
+                    .route("/{name}/versions", web::get().to(crate_versions_query)),
+            )
+            .service(
+                web::scope("/dependency")
+                    .route("", web::get().to(dependency_query))
+                    .route("/check", web::get().to(dependency_check_query))
+                    .route("/diff", web::get().to(dependency_diff_query))
+                    .route("/duplicates", web::get().to(dependency_duplicates_query))
+                    .route("/lockfile", web::get().to(dependency_lockfile_query))
+                    .route("/outdated", web::get().to(dependency_outdated_query))
+                    .route("/paths", web::get().to(dependency_paths_query))
+                    .route("/yanked", web::get().to(dependency_yanked_query)),
+            )
+            .service(
+                web::scope("/lockfile")
+                    .app_data(web::PayloadConfig::new(LOCKFILE_PAYLOAD_LIMIT))
+                    .route("/analyze", web::post().to(lockfile_analyze))
+                    .route("/check", web::post().to(lockfile_check)),
+            )
+            .service(web::scope("/manifest").route("/resolve", web::post().to(manifest_resolve)))
+            .app_data(advisories_configuration.clone())
+            .app_data(advisory_database.clone())
             .app_data(crates_io_client.clone())
+            .app_data(policy.clone())
             .app_data(postgres_client.clone())
             .app_data(postgres_pool.clone())
             .app_data(redis_pool.clone())
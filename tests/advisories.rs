@@ -0,0 +1,203 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn advisories_reload_returns_200() {
+    // Arrange
+    let app = spawn_app(&[
+        ("advisories.directory", "tests/fixtures/advisory-db"),
+        ("advisories.reload_token", "token"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .bearer_auth("token")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(serde_json::json!({"advisories": 1}), json);
+}
+
+#[actix_rt::test]
+async fn advisories_reload_returns_401_when_token_is_missing() {
+    // Arrange
+    let app = spawn_app(&[
+        ("advisories.directory", "tests/fixtures/advisory-db"),
+        ("advisories.reload_token", "token"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[actix_rt::test]
+async fn advisories_reload_returns_401_when_token_is_wrong() {
+    // Arrange
+    let app = spawn_app(&[
+        ("advisories.directory", "tests/fixtures/advisory-db"),
+        ("advisories.reload_token", "token"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .bearer_auth("wrong")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[actix_rt::test]
+async fn advisories_reload_returns_401_when_token_is_not_configured() {
+    // Arrange
+    let app = spawn_app(&[("advisories.directory", "tests/fixtures/advisory-db")]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .bearer_auth("token")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[actix_rt::test]
+async fn advisories_reload_returns_503_when_directory_is_not_configured() {
+    // Arrange
+    let app = spawn_app(&[("advisories.reload_token", "token")]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .bearer_auth("token")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 503);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_advisories() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[
+        ("advisories.directory", "tests/fixtures/advisory-db"),
+        ("advisories.reload_token", "token"),
+        ("crates_io.base_address", mock_server.uri().as_str()),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    client
+        .post(&format!("{}/admin/advisories/reload", app.address))
+        .bearer_auth("token")
+        .send()
+        .await
+        .unwrap();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("depth", "all"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let advisories = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| {
+            (
+                node["name"].as_str().unwrap(),
+                node["version"].as_str().unwrap(),
+                node["advisories"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("rust-kata-003", "0.1.0", serde_json::Value::Null),
+            ("proc-macro2", "1.0.24", serde_json::Value::Null),
+            (
+                "unicode-xid",
+                "0.1.0",
+                serde_json::json!([
+                    {"id": "RUSTSEC-0000-0001", "severity": "high", "patched": [">= 0.2.1"]}
+                ])
+            ),
+            ("unicode-xid", "0.2.1", serde_json::Value::Null),
+        ],
+        advisories
+    );
+}
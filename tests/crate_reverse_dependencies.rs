@@ -0,0 +1,304 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/unicode-xid/reverse_dependencies"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(fixture("unicode-xid-reverse-dependencies.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "proc-macro2",
+                    "version": "1.0.24",
+                    "relationship": "dependency.normal",
+                    "requirement": "^0.2",
+                    "optional": false
+                },
+                {
+                    "name": "xml-rs",
+                    "version": "0.3.6",
+                    "relationship": "dependency.normal",
+                    "requirement": "^0.0.3",
+                    "optional": false
+                },
+                {
+                    "name": "unicode-normalization",
+                    "version": "0.1.17",
+                    "relationship": "dependency.dev",
+                    "requirement": "^0.2",
+                    "optional": false
+                }
+            ],
+            "source": "crates_io"
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_200_with_matches_for_version() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/unicode-xid/reverse_dependencies"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(fixture("unicode-xid-reverse-dependencies.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .query(&[("version", "0.2.1"), ("page", "2")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let matches = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|dependent| {
+            (
+                dependent["name"].as_str().unwrap(),
+                dependent["matches"].as_bool().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("proc-macro2", true),
+            ("xml-rs", false),
+            ("unicode-normalization", true)
+        ],
+        matches
+    );
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_200_with_matches_for_requirement() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (url, filename) in &[
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/reverse_dependencies",
+            "unicode-xid-reverse-dependencies.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*url))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .query(&[("version", "<0.2")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let matches = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|dependent| dependent["matches"].as_bool().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![false, false, false], matches);
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_200_from_cache() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/proc-macro2/1.0.24/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("proc-macro2-1.0.24.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "proc-macro2"), ("version", "1.0.24")])
+        .send()
+        .await
+        .unwrap();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .query(&[("source", "cache"), ("version", "0.2.1")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "proc-macro2",
+                    "version": "1.0.24",
+                    "relationship": "dependency.normal",
+                    "requirement": "^0.2",
+                    "optional": false,
+                    "matches": true
+                }
+            ],
+            "source": "cache"
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_400_when_source_is_not_supported() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .query(&[("source", "github")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(
+        "github is not a supported source. Use either `cache` or `crates_io`.",
+        response.text().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_400_when_version_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/unicode-xid/reverse-dependencies",
+            app.address
+        ))
+        .query(&[("version", "newest")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn crate_reverse_dependencies_query_returns_404_when_crate_does_not_exist() {
+    // Arrange
+    let crate_name = format!("crate-{}", Uuid::new_v4());
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/crates/{}/reverse_dependencies",
+            crate_name
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!(
+            "{}/crates/{}/reverse-dependencies",
+            app.address, crate_name
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
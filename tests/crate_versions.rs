@@ -0,0 +1,131 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn crate_versions_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/unicode-xid"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("unicode-xid.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/crates/unicode-xid/versions", app.address))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let versions = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|version| version["version"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["0.1.0", "0.2.0", "0.2.1"], versions);
+    assert_eq!(
+        serde_json::json!({
+            "version": "0.2.1",
+            "created_at": "2020-06-09T02:31:02.432914+00:00",
+            "yanked": false,
+            "checksum": "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564",
+            "rust_version": null,
+            "license": "MIT OR Apache-2.0"
+        }),
+        json["data"][2]
+    );
+}
+
+#[actix_rt::test]
+async fn crate_versions_query_returns_200_from_cache() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/unicode-xid"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("unicode-xid.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    client
+        .get(&format!("{}/crates/unicode-xid/versions", app.address))
+        .send()
+        .await
+        .unwrap();
+
+    // Act
+    let response = client
+        .get(&format!("{}/crates/Unicode_Xid/versions", app.address))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(3, json["data"].as_array().unwrap().len());
+}
+
+#[actix_rt::test]
+async fn crate_versions_query_returns_400_when_name_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/crates/-invalid/versions", app.address))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn crate_versions_query_returns_404_when_crate_does_not_exist() {
+    // Arrange
+    let crate_name = format!("crate-{}", Uuid::new_v4());
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/crates/{}", crate_name)))
+        .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/crates/{}/versions", app.address, crate_name))
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
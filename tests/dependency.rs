@@ -4,6 +4,7 @@ mod support;
 use crate::fixtures::fixture;
 use crate::support::spawn_app;
 use fake::{Fake, Faker};
+use uuid::Uuid;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -33,6 +34,1288 @@ async fn dependency_query_returns_200() {
     assert_eq!(response.status().as_u16(), 200);
 }
 
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_latest_version() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "proc-macro2"), ("version", "latest")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!("1.0.24", json["data"][0]["version"]);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_highest_version_matching_requirement() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "proc-macro2"), ("version", "^1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!("1.0.24", json["data"][0]["version"]);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_transitive_dependencies() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/quote", "quote.json"),
+        (
+            "/api/v1/crates/quote/1.0.9/dependencies",
+            "quote-1.0.9.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("depth", "1"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "proc-macro2",
+                    "version": "1.0.24",
+                    "yanked": false,
+                    "license": "MIT OR Apache-2.0",
+                    "edges": [
+                        {
+                            "relationship": "dependency.dev",
+                            "optional": false,
+                            "default_features": false,
+                            "features": [],
+                            "node": {"name": "quote", "requirement": "^1.0", "version": "1.0.9"}
+                        },
+                        {
+                            "relationship": "dependency.normal",
+                            "optional": false,
+                            "default_features": true,
+                            "features": [],
+                            "node": {"name": "unicode-xid", "requirement": "^0.2", "version": "0.2.1"}
+                        }
+                    ]
+                },
+                {
+                    "name": "quote",
+                    "version": "1.0.9",
+                    "yanked": false,
+                    "license": "MIT OR Apache-2.0",
+                    "edges": [
+                        {
+                            "relationship": "dependency.normal",
+                            "optional": false,
+                            "default_features": false,
+                            "features": [],
+                            "node": {"name": "proc-macro2", "requirement": "^1.0.20"}
+                        },
+                        {
+                            "relationship": "dependency.dev",
+                            "optional": false,
+                            "default_features": true,
+                            "features": [],
+                            "node": {"name": "rustversion", "requirement": "^1.0"}
+                        },
+                        {
+                            "relationship": "dependency.dev",
+                            "optional": false,
+                            "default_features": true,
+                            "features": ["diff"],
+                            "node": {"name": "trybuild", "requirement": "^1.0.19"}
+                        }
+                    ]
+                },
+                {
+                    "name": "unicode-xid",
+                    "version": "0.2.1",
+                    "yanked": false,
+                    "license": "MIT OR Apache-2.0",
+                    "edges": []
+                }
+            ]
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_cycles() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/quote", "quote.json"),
+        (
+            "/api/v1/crates/quote/1.0.9/dependencies",
+            "quote-1.0.9.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("depth", "all"),
+            ("cycles", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(3, json["data"].as_array().unwrap().len());
+    assert_eq!(
+        serde_json::json!([
+            [
+                {
+                    "relationship": "dependency.dev",
+                    "from": {"name": "proc-macro2", "version": "1.0.24"},
+                    "to": {"name": "quote", "version": "1.0.9"}
+                },
+                {
+                    "relationship": "dependency.normal",
+                    "from": {"name": "quote", "version": "1.0.9"},
+                    "to": {"name": "proc-macro2", "version": "1.0.24"}
+                }
+            ]
+        ]),
+        json["cycles"]
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_without_dev_dependencies() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("depth", "all"),
+            ("dev_dependencies", "false"),
+            ("cycles", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let nodes = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["proc-macro2", "unicode-xid"], nodes);
+    assert_eq!(
+        serde_json::json!({"name": "quote", "requirement": "^1.0"}),
+        json["data"][0]["edges"][0]["node"]
+    );
+    assert_eq!(serde_json::json!([]), json["cycles"]);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_without_dependencies_for_other_targets() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/rand/0.8.3/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("rand-0.8.3.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rand"),
+            ("version", "0.8.3"),
+            ("target", "x86_64-unknown-linux-gnu"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let edges = json["data"][0]["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["relationship"].as_str().unwrap(),
+                edge["node"]["name"].as_str().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("dependency.dev", "bincode"),
+            ("dependency.normal", "libc"),
+            ("dependency.normal", "log"),
+            ("dependency.normal", "packed_simd_2"),
+            ("dependency.normal", "rand_chacha"),
+            ("dependency.normal", "rand_core"),
+            ("dependency.dev", "rand_hc"),
+            ("dependency.dev", "rand_pcg"),
+            ("dependency.normal", "serde"),
+        ],
+        edges
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_activated_default_features() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/rand", "rand.json"),
+        ("/api/v1/crates/rand/0.8.3/dependencies", "rand-0.8.3.json"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rand"),
+            ("version", "0.8.3"),
+            ("target", "x86_64-unknown-linux-gnu"),
+            ("features", ""),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!([
+            "alloc",
+            "default",
+            "getrandom",
+            "rand_chacha",
+            "std",
+            "std_rng"
+        ]),
+        json["data"][0]["features"]
+    );
+
+    let edges = json["data"][0]["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["node"]["name"].as_str().unwrap(),
+                edge["activated_features"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("bincode", serde_json::json!(["default"])),
+            ("libc", serde_json::json!([])),
+            ("rand_chacha", serde_json::json!(["std"])),
+            (
+                "rand_core",
+                serde_json::json!(["alloc", "default", "getrandom", "std"])
+            ),
+            ("rand_hc", serde_json::json!(["default"])),
+            ("rand_pcg", serde_json::json!(["default"])),
+        ],
+        edges
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_requested_features_only() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/rand", "rand.json"),
+        ("/api/v1/crates/rand/0.8.3/dependencies", "rand-0.8.3.json"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rand"),
+            ("version", "0.8.3"),
+            ("features", "serde1"),
+            ("no_default_features", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!(["serde", "serde1"]),
+        json["data"][0]["features"]
+    );
+
+    let edges = json["data"][0]["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["node"]["name"].as_str().unwrap(),
+                edge["activated_features"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("bincode", serde_json::json!(["default"])),
+            ("libc", serde_json::json!([])),
+            ("rand_core", serde_json::json!(["default"])),
+            ("rand_hc", serde_json::json!(["default"])),
+            ("rand_pcg", serde_json::json!(["default"])),
+            ("serde", serde_json::json!(["default", "derive"])),
+        ],
+        edges
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_yanked_version_when_requested_explicitly() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/unicode-xid", "unicode-xid-yanked.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "unicode-xid"), ("version", "0.2.1")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!("0.2.1", json["data"][0]["version"]);
+    assert_eq!(Some(true), json["data"][0]["yanked"].as_bool());
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_without_yanked_version_when_resolving_requirement() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/unicode-xid", "unicode-xid-yanked.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.0/dependencies",
+            "unicode-xid-0.2.0.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "unicode-xid"), ("version", "^0.2")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!("0.2.0", json["data"][0]["version"]);
+    assert_eq!(Some(false), json["data"][0]["yanked"].as_bool());
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_summary() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("depth", "all"),
+            ("summary", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "crates": 3,
+            "packages": 4,
+            "max_depth": 2,
+            "longest_chain": [
+                {"name": "rust-kata-003", "version": "0.1.0"},
+                {"name": "proc-macro2", "version": "1.0.24"},
+                {"name": "unicode-xid", "version": "0.2.1"}
+            ],
+            "edges": {"normal": 3},
+            "nodes": [
+                {"name": "rust-kata-003", "version": "0.1.0", "depth": 0, "fan_in": 0, "fan_out": 2},
+                {"name": "proc-macro2", "version": "1.0.24", "depth": 1, "fan_in": 1, "fan_out": 1},
+                {"name": "unicode-xid", "version": "0.1.0", "depth": 1, "fan_in": 1, "fan_out": 0},
+                {"name": "unicode-xid", "version": "0.2.1", "depth": 2, "fan_in": 1, "fan_out": 0}
+            ],
+            "direct_dependencies": [
+                {"name": "proc-macro2", "version": "1.0.24", "weight": 2},
+                {"name": "unicode-xid", "version": "0.1.0", "weight": 1}
+            ]
+        }),
+        json["summary"]
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_200_with_licenses() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("depth", "all"),
+            ("licenses", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(Some("MIT/Apache-2.0"), json["data"][2]["license"].as_str());
+    assert_eq!(
+        serde_json::json!([
+            {
+                "expression": "Apache-2.0 OR MIT",
+                "licenses": ["Apache-2.0", "MIT"],
+                "crates": [
+                    {"name": "proc-macro2", "version": "1.0.24"},
+                    {"name": "unicode-xid", "version": "0.1.0"},
+                    {"name": "unicode-xid", "version": "0.2.1"}
+                ]
+            },
+            {
+                "expression": null,
+                "licenses": null,
+                "crates": [{"name": "rust-kata-003", "version": "0.1.0"}]
+            }
+        ]),
+        json["licenses"]
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_yanked_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/unicode-xid", "unicode-xid-yanked.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/yanked", app.address))
+        .query(&[("name", "unicode-xid"), ("version", "0.2.1")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [{"name": "unicode-xid", "version": "0.2.1"}]
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_duplicates_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/duplicates", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "unicode-xid",
+                    "versions": [
+                        {
+                            "version": "0.1.0",
+                            "dependents": [
+                                {
+                                    "relationship": "dependency.normal",
+                                    "requirement": "^0.1",
+                                    "node": {"name": "rust-kata-003", "version": "0.1.0"}
+                                }
+                            ]
+                        },
+                        {
+                            "version": "0.2.1",
+                            "dependents": [
+                                {
+                                    "relationship": "dependency.normal",
+                                    "requirement": "^0.2",
+                                    "node": {"name": "proc-macro2", "version": "1.0.24"}
+                                }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_duplicates_query_returns_200_without_duplicates() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/duplicates", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("depth", "all"),
+            ("dev_dependencies", "false"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({"data": []})
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_duplicates_query_returns_200_within_depth() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/duplicates", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("depth", "1"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({"data": []})
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_lockfile_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/lockfile", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        "application/toml",
+        response.headers()["content-type"].to_str().unwrap()
+    );
+    assert_eq!(
+        r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"
+dependencies = [
+ "unicode-xid 0.2.1",
+]
+
+[[package]]
+name = "rust-kata-003"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc72304796d0818e357ead4e000d19c9c174ab23dc11093ac919054d20a6a7fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"
+"#,
+        response.text().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_lockfile_query_returns_200_without_inactive_optional_dependencies() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/rand", "rand.json"),
+        ("/api/v1/crates/rand/0.8.3/dependencies", "rand-0.8.3.json"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    for relative_path in &[
+        "/api/v1/crates/log",
+        "/api/v1/crates/packed_simd_2",
+        "/api/v1/crates/serde",
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/lockfile", app.address))
+        .query(&[
+            ("name", "rand"),
+            ("version", "0.8.3"),
+            ("target", "x86_64-unknown-linux-gnu"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+}
+
+#[actix_rt::test]
+async fn dependency_paths_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/paths", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("dependency", "unicode-xid"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [
+                [
+                    {
+                        "relationship": "dependency.normal",
+                        "requirement": "^1.0",
+                        "from": {"name": "rust-kata-003", "version": "0.1.0"},
+                        "to": {"name": "proc-macro2", "version": "1.0.24"}
+                    },
+                    {
+                        "relationship": "dependency.normal",
+                        "requirement": "^0.2",
+                        "from": {"name": "proc-macro2", "version": "1.0.24"},
+                        "to": {"name": "unicode-xid", "version": "0.2.1"}
+                    }
+                ],
+                [
+                    {
+                        "relationship": "dependency.normal",
+                        "requirement": "^0.1",
+                        "from": {"name": "rust-kata-003", "version": "0.1.0"},
+                        "to": {"name": "unicode-xid", "version": "0.1.0"}
+                    }
+                ]
+            ],
+            "truncated": false
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_paths_query_returns_400_when_dependency_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/paths", app.address))
+        .query(&[
+            ("name", "rust-kata-003"),
+            ("version", "0.1.0"),
+            ("dependency", "-unicode-xid"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_400_when_target_is_not_supported() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "rand"),
+            ("version", "0.8.3"),
+            ("target", "sparc-unknown-unknown"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_400_when_depth_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("depth", "other"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_400_when_name_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "proc-macro2/1.0.24"), ("version", "1.0.24")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn dependency_query_returns_400_when_version_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", Faker.fake::<String>().as_str()),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
 #[actix_rt::test]
 async fn dependency_query_returns_400_when_data_is_missing() {
     // Arrange
@@ -62,11 +1345,37 @@ async fn dependency_query_returns_400_when_data_is_missing() {
     }
 }
 
+#[actix_rt::test]
+async fn dependency_query_returns_404_when_no_version_matches_requirement() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/proc-macro2"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("proc-macro2.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "proc-macro2"), ("version", "^2")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
 #[actix_rt::test]
 async fn dependency_query_returns_404_when_crate_data_does_not_exist() {
     // Arrange
-    let crate_name = Faker.fake::<String>();
-    let crate_version = Faker.fake::<String>();
+    let crate_name = format!("crate-{}", Uuid::new_v4());
+    let crate_version = "1.0.0".to_owned();
 
     let relative_path = format!(
         "/api/v1/crates/{}/{}/dependencies",
@@ -95,3 +1404,29 @@ async fn dependency_query_returns_404_when_crate_data_does_not_exist() {
     // Assert
     assert_eq!(404, response.status().as_u16());
 }
+
+#[actix_rt::test]
+async fn dependency_query_returns_500_when_crate_data_cannot_be_parsed() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/unparseable/1.0.0/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("unparseable-1.0.0.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency", app.address))
+        .query(&[("name", "unparseable"), ("version", "1.0.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(500, response.status().as_u16());
+}
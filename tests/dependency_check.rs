@@ -0,0 +1,204 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn dependency_check_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[
+        ("crates_io.base_address", mock_server.uri().as_str()),
+        ("policy.file", "tests/fixtures/policy.toml"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/check", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("dev_dependencies", "false"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(serde_json::json!({"violations": []}), json);
+}
+
+#[actix_rt::test]
+async fn dependency_check_query_returns_422_with_violations() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[
+        ("crates_io.base_address", mock_server.uri().as_str()),
+        ("policy.file", "tests/fixtures/policy.toml"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/check", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 422);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "violations": [
+                {
+                    "rule": "license",
+                    "message": "rust-kata-003 0.1.0 has no license expression.",
+                    "node": {"name": "rust-kata-003", "version": "0.1.0"},
+                    "path": []
+                },
+                {
+                    "rule": "ban",
+                    "message": "unicode-xid 0.1.0 is banned by the policy for versions `< 0.2`.",
+                    "node": {"name": "unicode-xid", "version": "0.1.0"},
+                    "path": [
+                        {
+                            "relationship": "dependency.normal",
+                            "requirement": "^0.1",
+                            "from": {"name": "rust-kata-003", "version": "0.1.0"},
+                            "to": {"name": "unicode-xid", "version": "0.1.0"}
+                        }
+                    ]
+                },
+                {
+                    "rule": "duplicate",
+                    "message": "unicode-xid 0.1.0 is one of several semver incompatible versions of unicode-xid.",
+                    "node": {"name": "unicode-xid", "version": "0.1.0"},
+                    "path": [
+                        {
+                            "relationship": "dependency.normal",
+                            "requirement": "^0.1",
+                            "from": {"name": "rust-kata-003", "version": "0.1.0"},
+                            "to": {"name": "unicode-xid", "version": "0.1.0"}
+                        }
+                    ]
+                },
+                {
+                    "rule": "duplicate",
+                    "message": "unicode-xid 0.2.1 is one of several semver incompatible versions of unicode-xid.",
+                    "node": {"name": "unicode-xid", "version": "0.2.1"},
+                    "path": [
+                        {
+                            "relationship": "dependency.normal",
+                            "requirement": "^1.0",
+                            "from": {"name": "rust-kata-003", "version": "0.1.0"},
+                            "to": {"name": "proc-macro2", "version": "1.0.24"}
+                        },
+                        {
+                            "relationship": "dependency.normal",
+                            "requirement": "^0.2",
+                            "from": {"name": "proc-macro2", "version": "1.0.24"},
+                            "to": {"name": "unicode-xid", "version": "0.2.1"}
+                        }
+                    ]
+                }
+            ]
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_check_query_returns_503_when_policy_is_not_configured() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/check", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 503);
+}
+
+#[actix_rt::test]
+async fn dependency_check_query_returns_503_when_policy_cannot_be_read() {
+    // Arrange
+    let app = spawn_app(&[("policy.file", "tests/fixtures/not-found.toml")]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/check", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 503);
+}
@@ -0,0 +1,221 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn dependency_diff_query_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/proc-macro2/0.4.30/dependencies",
+            "proc-macro2-0.4.30.json",
+        ),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/diff", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("from", "0.4.30"),
+            ("to", "1.0.24"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "name": "proc-macro2",
+            "from": "0.4.30",
+            "to": "1.0.24",
+            "dependencies": {
+                "added": [],
+                "removed": [],
+                "changed": [
+                    {
+                        "name": "quote",
+                        "from": {
+                            "name": "quote",
+                            "relationship": "dependency.dev",
+                            "requirement": "^0.6",
+                            "optional": false,
+                            "default_features": true,
+                            "features": []
+                        },
+                        "to": {
+                            "name": "quote",
+                            "relationship": "dependency.dev",
+                            "requirement": "^1.0",
+                            "optional": false,
+                            "default_features": false,
+                            "features": []
+                        }
+                    },
+                    {
+                        "name": "unicode-xid",
+                        "from": {
+                            "name": "unicode-xid",
+                            "relationship": "dependency.normal",
+                            "requirement": "^0.1",
+                            "optional": false,
+                            "default_features": true,
+                            "features": []
+                        },
+                        "to": {
+                            "name": "unicode-xid",
+                            "relationship": "dependency.normal",
+                            "requirement": "^0.2",
+                            "optional": false,
+                            "default_features": true,
+                            "features": []
+                        }
+                    }
+                ]
+            }
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_diff_query_returns_200_with_transitive_packages() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/0.4.30/dependencies",
+            "proc-macro2-0.4.30.json",
+        ),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/diff", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("from", "0.4.30"),
+            ("to", "1.0.24"),
+            ("transitive", "true"),
+            ("dev_dependencies", "false"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "added": [],
+            "removed": [],
+            "changed": [
+                {"name": "unicode-xid", "from": ["0.1.0"], "to": ["0.2.1"]}
+            ]
+        }),
+        json["packages"]
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_diff_query_returns_400_when_name_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/diff", app.address))
+        .query(&[
+            ("name", "-proc-macro2"),
+            ("from", "0.4.30"),
+            ("to", "1.0.24"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn dependency_diff_query_returns_404_when_version_does_not_exist() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/proc-macro2/1.0.24/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("proc-macro2-1.0.24.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/proc-macro2/9.9.9/dependencies"))
+        .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/diff", app.address))
+        .query(&[("name", "proc-macro2"), ("from", "1.0.24"), ("to", "9.9.9")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+}
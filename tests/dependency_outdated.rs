@@ -0,0 +1,171 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[actix_rt::test]
+async fn dependency_outdated_query_returns_200_for_direct_dependencies() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        (
+            "/api/v1/crates/rust-kata-003/0.1.0/dependencies",
+            "rust-kata-003-0.1.0.json",
+        ),
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/outdated", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "proc-macro2",
+                    "relationship": "dependency.normal",
+                    "requirement": "^1.0",
+                    "dependent": {"name": "rust-kata-003", "version": "0.1.0"},
+                    "resolved": "1.0.24",
+                    "compatible": "1.0.24",
+                    "latest": "1.0.24",
+                    "requires_bump": false
+                },
+                {
+                    "name": "unicode-xid",
+                    "relationship": "dependency.normal",
+                    "requirement": "^0.1",
+                    "dependent": {"name": "rust-kata-003", "version": "0.1.0"},
+                    "resolved": "0.1.0",
+                    "compatible": "0.1.0",
+                    "latest": "0.2.1",
+                    "requires_bump": true
+                }
+            ]
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_outdated_query_returns_200_for_transitive_dependencies() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/quote", "quote.json"),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/outdated", app.address))
+        .query(&[
+            ("name", "proc-macro2"),
+            ("version", "1.0.24"),
+            ("dev_dependencies", "false"),
+            ("transitive", "true"),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "data": [
+                {
+                    "name": "quote",
+                    "relationship": "dependency.dev",
+                    "requirement": "^1.0",
+                    "dependent": {"name": "proc-macro2", "version": "1.0.24"},
+                    "resolved": "1.0.9",
+                    "compatible": "1.0.9",
+                    "latest": "1.0.9",
+                    "requires_bump": false
+                },
+                {
+                    "name": "unicode-xid",
+                    "relationship": "dependency.normal",
+                    "requirement": "^0.2",
+                    "dependent": {"name": "proc-macro2", "version": "1.0.24"},
+                    "resolved": "0.2.1",
+                    "compatible": "0.2.1",
+                    "latest": "0.2.1",
+                    "requires_bump": false
+                }
+            ]
+        }),
+        json
+    );
+}
+
+#[actix_rt::test]
+async fn dependency_outdated_query_returns_404_for_unknown_crate() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/crates/rust-kata-003/0.1.0/dependencies"))
+        .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .get(&format!("{}/dependency/outdated", app.address))
+        .query(&[("name", "rust-kata-003"), ("version", "0.1.0")])
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+}
@@ -0,0 +1,227 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const LOCKFILE: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"
+dependencies = [
+ "unicode-xid 0.2.1",
+]
+
+[[package]]
+name = "rust-kata-003"
+version = "0.1.0"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc72304796d0818e357ead4e000d19c9c174ab23dc11093ac919054d20a6a7fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"
+"#;
+
+#[actix_rt::test]
+async fn lockfile_analyze_returns_200() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/analyze", app.address))
+        .body(LOCKFILE)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "name": "proc-macro2",
+            "version": "1.0.24",
+            "yanked": false,
+            "license": "MIT OR Apache-2.0",
+            "edges": [
+                {
+                    "relationship": "dependency.dev",
+                    "optional": false,
+                    "default_features": false,
+                    "features": [],
+                    "node": {"name": "quote", "requirement": "^1.0"}
+                },
+                {
+                    "relationship": "dependency.normal",
+                    "optional": false,
+                    "default_features": true,
+                    "features": [],
+                    "node": {"name": "unicode-xid", "requirement": "^0.2", "version": "0.2.1"}
+                }
+            ]
+        }),
+        json["data"][0]
+    );
+    assert_eq!(
+        serde_json::json!({
+            "name": "rust-kata-003",
+            "version": "0.1.0",
+            "yanked": null,
+            "license": null,
+            "edges": [
+                {
+                    "relationship": "dependency.normal",
+                    "optional": false,
+                    "default_features": true,
+                    "features": [],
+                    "node": {"name": "proc-macro2", "requirement": "=1.0.24", "version": "1.0.24"}
+                },
+                {
+                    "relationship": "dependency.normal",
+                    "optional": false,
+                    "default_features": true,
+                    "features": [],
+                    "node": {"name": "unicode-xid", "requirement": "=0.1.0", "version": "0.1.0"}
+                }
+            ]
+        }),
+        json["data"][1]
+    );
+    assert_eq!("0.2.1", json["data"][2]["latest_version"]);
+    assert_eq!(serde_json::Value::Null, json["data"][3]["latest_version"]);
+    assert_eq!(
+        serde_json::json!([
+            {
+                "name": "unicode-xid",
+                "versions": [
+                    {
+                        "version": "0.1.0",
+                        "dependents": [
+                            {
+                                "relationship": "dependency.normal",
+                                "requirement": "=0.1.0",
+                                "node": {"name": "rust-kata-003", "version": "0.1.0"}
+                            }
+                        ]
+                    },
+                    {
+                        "version": "0.2.1",
+                        "dependents": [
+                            {
+                                "relationship": "dependency.normal",
+                                "requirement": "^0.2",
+                                "node": {"name": "proc-macro2", "version": "1.0.24"}
+                            }
+                        ]
+                    }
+                ]
+            }
+        ]),
+        json["duplicates"]
+    );
+    assert_eq!(
+        serde_json::json!([
+            {
+                "expression": "Apache-2.0 OR MIT",
+                "licenses": ["Apache-2.0", "MIT"],
+                "crates": [
+                    {"name": "proc-macro2", "version": "1.0.24"},
+                    {"name": "unicode-xid", "version": "0.1.0"},
+                    {"name": "unicode-xid", "version": "0.2.1"}
+                ]
+            },
+            {
+                "expression": null,
+                "licenses": null,
+                "crates": [{"name": "rust-kata-003", "version": "0.1.0"}]
+            }
+        ]),
+        json["licenses"]
+    );
+}
+
+#[actix_rt::test]
+async fn lockfile_analyze_returns_400_when_lockfile_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/analyze", app.address))
+        .body("[[package]]\nname = \"proc-macro2\"")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn lockfile_analyze_returns_400_when_dependency_is_not_locked() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/analyze", app.address))
+        .body("[[package]]\nname = \"rust-kata-003\"\nversion = \"0.1.0\"\ndependencies = [\"proc-macro2\"]")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(
+        "proc-macro2 is not a locked package. Use the name and version of a package in Cargo.lock.",
+        response.text().await.unwrap()
+    );
+}
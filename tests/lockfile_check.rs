@@ -0,0 +1,158 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const LOCKFILE: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"
+dependencies = [
+ "unicode-xid 0.2.1",
+]
+
+[[package]]
+name = "rust-kata-003"
+version = "0.1.0"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc72304796d0818e357ead4e000d19c9c174ab23dc11093ac919054d20a6a7fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"
+"#;
+
+#[actix_rt::test]
+async fn lockfile_check_returns_422_with_violations() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let app = spawn_app(&[
+        ("crates_io.base_address", mock_server.uri().as_str()),
+        ("policy.file", "tests/fixtures/policy.toml"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/check", app.address))
+        .body(LOCKFILE)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 422);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    let violations = json["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|violation| {
+            (
+                violation["rule"].as_str().unwrap(),
+                violation["node"]["name"].as_str().unwrap(),
+                violation["node"]["version"].as_str().unwrap(),
+                violation["path"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|edge| edge["to"]["name"].as_str().unwrap())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("license", "rust-kata-003", "0.1.0", vec![]),
+            ("ban", "unicode-xid", "0.1.0", vec!["unicode-xid"]),
+            ("duplicate", "unicode-xid", "0.1.0", vec!["unicode-xid"]),
+            (
+                "duplicate",
+                "unicode-xid",
+                "0.2.1",
+                vec!["proc-macro2", "unicode-xid"]
+            ),
+        ],
+        violations
+    );
+}
+
+#[actix_rt::test]
+async fn lockfile_check_returns_400_when_lockfile_is_invalid() {
+    // Arrange
+    let app = spawn_app(&[("policy.file", "tests/fixtures/policy.toml")]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/check", app.address))
+        .body("[[package]]\nname = \"proc-macro2\"")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[actix_rt::test]
+async fn lockfile_check_returns_503_when_policy_is_not_configured() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/lockfile/check", app.address))
+        .body(LOCKFILE)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 503);
+}
@@ -0,0 +1,230 @@
+mod fixtures;
+mod support;
+
+use crate::fixtures::fixture;
+use crate::support::spawn_app;
+use wiremock::matchers::{any, method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const MANIFEST: &str = r#"
+[package]
+name = "internal-service"
+version = "0.3.0"
+edition = "2018"
+license = "Apache-2.0"
+
+[dependencies]
+proc-macro2 = "1.0"
+xid = { version = "0.1", package = "unicode-xid" }
+"#;
+
+async fn mock_crates_io() -> MockServer {
+    let mock_server = MockServer::start().await;
+    for (relative_path, filename) in &[
+        ("/api/v1/crates/proc-macro2", "proc-macro2.json"),
+        (
+            "/api/v1/crates/proc-macro2/1.0.24/dependencies",
+            "proc-macro2-1.0.24.json",
+        ),
+        ("/api/v1/crates/unicode-xid", "unicode-xid.json"),
+        (
+            "/api/v1/crates/unicode-xid/0.1.0/dependencies",
+            "unicode-xid-0.1.0.json",
+        ),
+        (
+            "/api/v1/crates/unicode-xid/0.2.1/dependencies",
+            "unicode-xid-0.2.1.json",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(*relative_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(filename)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    Mock::given(path_regex("^/api/v1/crates/internal-service"))
+        .respond_with(ResponseTemplate::new(404).set_body_bytes(fixture("404.json")))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+#[actix_rt::test]
+async fn manifest_resolve_returns_200() {
+    // Arrange
+    let mock_server = mock_crates_io().await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/manifest/resolve", app.address))
+        .body(MANIFEST)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+
+    let json = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "name": "internal-service",
+            "version": "0.3.0",
+            "yanked": null,
+            "license": "Apache-2.0",
+            "edges": [
+                {
+                    "relationship": "dependency.normal",
+                    "optional": false,
+                    "default_features": true,
+                    "features": [],
+                    "node": {"name": "proc-macro2", "requirement": "1.0", "version": "1.0.24"}
+                },
+                {
+                    "relationship": "dependency.normal",
+                    "optional": false,
+                    "default_features": true,
+                    "features": [],
+                    "node": {"name": "unicode-xid", "requirement": "0.1", "version": "0.1.0"}
+                }
+            ]
+        }),
+        json["data"][0]
+    );
+    let nodes = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| {
+            (
+                node["name"].as_str().unwrap(),
+                node["version"].as_str().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("internal-service", "0.3.0"),
+            ("proc-macro2", "1.0.24"),
+            ("unicode-xid", "0.1.0"),
+            ("unicode-xid", "0.2.1"),
+        ],
+        nodes
+    );
+}
+
+#[actix_rt::test]
+async fn manifest_resolve_returns_200_with_lockfile() {
+    // Arrange
+    let mock_server = mock_crates_io().await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/manifest/resolve", app.address))
+        .query(&[("format", "lockfile")])
+        .body(MANIFEST)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "internal-service"
+version = "0.3.0"
+dependencies = [
+ "proc-macro2",
+ "unicode-xid 0.1.0",
+]
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.24"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"
+dependencies = [
+ "unicode-xid 0.2.1",
+]
+
+[[package]]
+name = "unicode-xid"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc72304796d0818e357ead4e000d19c9c174ab23dc11093ac919054d20a6a7fc"
+
+[[package]]
+name = "unicode-xid"
+version = "0.2.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"
+"#,
+        response.text().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn manifest_resolve_returns_400_when_manifest_is_invalid() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let app = spawn_app(&[("crates_io.base_address", mock_server.uri().as_str())]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/manifest/resolve", app.address))
+        .body("[package]\nname = \"internal-service\"\n[dependencies]\nother = { path = \"../other\" }")
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(
+        "other has no version requirement. Only dependencies published on crates.io can be resolved.",
+        response.text().await.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn manifest_resolve_returns_400_when_format_is_not_supported() {
+    // Arrange
+    let app = spawn_app(&[]).await;
+    let client = reqwest::Client::new();
+
+    // Act
+    let response = client
+        .post(&format!("{}/manifest/resolve", app.address))
+        .query(&[("format", "yaml")])
+        .body(MANIFEST)
+        .send()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(
+        "yaml is not a supported format. Use either `json` or `lockfile`.",
+        response.text().await.unwrap()
+    );
+}